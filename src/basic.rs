@@ -3,7 +3,7 @@
 use rust_decimal::prelude::*;
 
 // Modules
-use crate::error::Error;
+use crate::error::{ Error, ErrorKind };
 use crate::euler::{ d_exp, d_ln };
 
 //##########################################################################################################################
@@ -11,6 +11,58 @@ use crate::euler::{ d_exp, d_ln };
 const D0: Decimal = Decimal::ZERO;
 const D1: Decimal = Decimal::ONE;
 
+// Upper bound of terms when converging to a number of digits
+const MAX_TERMS: usize = 127;
+
+//##########################################################################################################################
+
+/// Length of a series: a fixed number of terms, or the number of
+/// decimal digits the series must converge to.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Terms {
+    /// Sum exactly this many terms
+    Fixed(usize),
+    /// Sum until the next term falls below `10^-(digits + 1)`
+    Digits(u32),
+}
+
+//##########################################################################################################################
+
+/// Sum the series `term(first) + term(first + 1) + ...` up to the length
+/// given by `terms`. Returns the sum and the number of terms used.
+#[inline]
+pub fn d_series<F>(
+    first: usize,
+    terms: Terms,
+    mut term: F
+) -> Result<(Decimal, usize), Error>
+where F: FnMut(usize) -> Result<Decimal, Error> {
+    match terms {
+        Terms::Fixed(count) => {
+            let sum = (first..(first + count))
+                .map(&mut term)
+                .reduce(|u, d| Ok(
+                    u?.checked_add(d?).ok_or(ErrorKind::AddOverflow)?
+                ))
+                .unwrap_or(Ok(D0))?;
+            Ok((sum, count))
+        },
+        Terms::Digits(digits) => {
+            if digits > 28 { Err(ErrorKind::InputOutOfRange)? };
+            let tolerance = Decimal::new(1, if digits < 28 {digits + 1} else {28});
+            let mut sum: Decimal = D0;
+            let mut n: usize = first;
+            loop {
+                if (n - first) >= MAX_TERMS {break Err(ErrorKind::NotConverged.into())};
+                let value = term(n)?;
+                sum = sum.checked_add(value).ok_or(ErrorKind::AddOverflow)?;
+                n += 1;
+                if value.abs() < tolerance {break Ok((sum, n - first))};
+            }
+        },
+    }
+}
+
 //##########################################################################################################################
 
 #[inline]
@@ -25,12 +77,12 @@ fn d_pow_helper(
     value: Decimal,
     power: usize
 ) -> Result<Decimal, Error> {
-    (1..=power).into_iter()
+    (1..=power)
         .map(|_| Ok(value))
         .reduce(|u, d| Ok(
-            u?.checked_mul(d?).ok_or(Error::MultiplyOverflow)?
+            u?.checked_mul(d?).ok_or(ErrorKind::MultiplyOverflow)?
         ))
-        .unwrap_or(Err(Error::IteratorError))
+        .unwrap_or(Err(ErrorKind::IteratorError.into()))
 }
 
 #[inline]
@@ -45,7 +97,10 @@ pub fn d_pow(
             _ => {
                      if value == D0 {D0}
                 else if value == D1 {D1}
-                else { d_pow_helper(value, power)? }
+                else {
+                    d_pow_helper(value, power)
+                        .map_err(|e| e.context("d_pow", format!("{}, {}", value, power)))?
+                }
             },
         }
     )
@@ -63,11 +118,11 @@ pub fn da_pow(
     let exp = if base.1 > power {power} else {base.1};
     let dif = d_pow(value, power - exp)?;
     // Calculate Result
-    let result = base.0.clone()
+    let result = base.0
         .checked_mul(dif)
-        .ok_or(Error::MultiplyOverflow)?;
+        .ok_or(ErrorKind::MultiplyOverflow)?;
     // Update Base
-    base.0 = result.clone();
+    base.0 = result;
     base.1 = power;
     // Return Result
     Ok(result)
@@ -82,8 +137,10 @@ pub fn dd_pow(
     power: Decimal,
     terms: usize
 ) -> Result<Decimal, Error> {
-    let ln_val = d_ln(value, terms)?;
-    d_exp(ln_val * power, terms)
+    let ctx = |e: Error| e.context("dd_pow", format!("{}, {}", value, power));
+    let ln_val = d_ln(value, terms).map_err(ctx)?;
+    let exponent = ln_val.checked_mul(power).ok_or(ErrorKind::MultiplyOverflow).map_err(|e| ctx(e.into()))?;
+    d_exp(exponent, terms).map_err(ctx)
 }
 
 //##########################################################################################################################
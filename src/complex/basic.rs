@@ -3,7 +3,7 @@
 use rust_decimal::prelude::*;
 
 // Modules
-use crate::error::Error;
+use crate::error::{ Error, ErrorKind };
 use crate::basic::{ dec };
 
 use crate::complex::types::{ Complex };
@@ -30,10 +30,10 @@ fn c_pow_series(
     value: Complex,
     power: usize
 ) -> Result<Complex, Error> {
-    (1..=power).into_iter()
+    (1..=power)
         .map(|_| Ok(value))
-        .reduce(|u, d| Ok(u? * d?))
-        .unwrap_or(Err(Error::IteratorError))
+        .reduce(|u, d| u?.checked_mul(d?))
+        .unwrap_or(Err(ErrorKind::IteratorError.into()))
 }
 
 #[inline]
@@ -48,7 +48,10 @@ pub fn c_pow(
             _ => {
                      if value == C0 {C0}
                 else if value == C1 {C1}
-                else { c_pow_series(value, power)? }
+                else {
+                    c_pow_series(value, power)
+                        .map_err(|e| e.context("c_pow", format!("{}, {}", value, power)))?
+                }
             },
         }
     )
@@ -63,8 +66,11 @@ pub fn cc_pow(
     power: Complex,
     terms: usize
 ) -> Result<Complex, Error> {
-    let ln_val = c_ln(value, terms)?;
-    c_exp(ln_val * power, terms)
+    let input = *value;
+    let ctx = |e: Error| e.context("cc_pow", format!("{}, {}", input, power));
+    let ln_val = c_ln(value, terms).map_err(ctx)?;
+    let exponent = ln_val.checked_mul(power).map_err(ctx)?;
+    c_exp(exponent, terms).map_err(ctx)
 }
 
 //##########################################################################################################################
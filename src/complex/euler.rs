@@ -21,15 +21,13 @@ pub fn c_exp(
     value: Complex,
     terms: usize
 ) -> Result<Complex, Error> {
+    let ctx = |e: Error| e.context("c_exp", value);
     // Calculate Variables
-    let cos_im = d_cos(value.im(), terms)?;
-    let sin_im = d_sin(value.im(), terms)?;
-    let exp_re = d_exp(value.re(), terms)?;
+    let cos_im = d_cos(value.im(), terms).map_err(ctx)?;
+    let sin_im = d_sin(value.im(), terms).map_err(ctx)?;
+    let exp_re = d_exp(value.re(), terms).map_err(ctx)?;
     // Calculate Complex
-    let re = exp_re * cos_im;
-    let im = exp_re * sin_im;
-    // Return Result
-    Ok(Complex::new(re, im))
+    Complex::new(cos_im, sin_im).checked_scale(exp_re).map_err(ctx)
 }
 
 //##########################################################################################################################
@@ -39,9 +37,11 @@ pub fn c_ln(
     value: &mut Complex,
     terms: usize
 ) -> Result<Complex, Error> {
+    let input = *value;
+    let ctx = |e: Error| e.context("c_ln", input);
     // Calculate Complex
-    let re = d_ln(value.radius_sqr(), terms)? / D2;
-    let im = value.arg(terms)?;
+    let re = d_ln(value.checked_radius_sqr().map_err(ctx)?, terms).map_err(ctx)? / D2;
+    let im = value.arg(terms).map_err(ctx)?;
     // Return Result
     Ok(Complex::new(re, im))
 }
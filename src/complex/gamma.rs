@@ -0,0 +1,108 @@
+
+// Imports
+use rust_decimal::prelude::*;
+use rust_decimal_macros::dec;
+
+// Modules
+use crate::constants::{ PI, PI2, LN_2, LN_PI, LN_SQRT_PI2 };
+use crate::error::{ Error };
+
+use crate::special::gamma::{ STIRLING, d_gamma, d_lgamma };
+
+use crate::complex::types::{ Complex };
+use crate::complex::euler::{ c_exp, c_ln };
+
+//##########################################################################################################################
+
+// Constants
+const D0: Decimal = Decimal::ZERO;
+const D1DIV2: Decimal = dec!(0.5);
+
+const C0: Complex = Complex::ZERO;
+const C1: Complex = Complex::ONE;
+
+// Smallest |z| where Stirling's series reaches full precision
+const STIRLING_LOWER_BD: Decimal = dec!(20);
+
+//##########################################################################################################################
+
+/// ln(Γ(z)) = (z - 1/2) * ln(z) - z + ln(2π)/2 + sum(k=1; B_2k / (2k * (2k - 1) * z^(2k - 1))), for re(z) > 0
+#[inline]
+pub(crate) fn lgamma_stirling(
+    value: Complex,
+    terms: usize
+) -> Result<Complex, Error> {
+    // ln(Γ(z)) = ln(Γ(z + m)) - sum(k=0; m-1; ln(z + k))
+    let mut z = value;
+    let mut shift = C0;
+    while z.checked_radius_sqr()? < (STIRLING_LOWER_BD * STIRLING_LOWER_BD) {
+        shift = shift.checked_add(c_ln(&mut z.clone(), terms)?)?;
+        z = z.checked_add(C1)?;
+    };
+    // Asymptotic series in 1/z
+    let inv = z.checked_inv()?;
+    let inv_sqr = inv.checked_mul(inv)?;
+    let mut pow = inv;
+    let mut sum = C0;
+    for c in STIRLING {
+        sum = sum.checked_add(pow.checked_scale(c)?)?;
+        pow = pow.checked_mul(inv_sqr)?;
+    };
+    let ln_z = c_ln(&mut z.clone(), terms)?;
+    z.checked_sub(C1 * D1DIV2)?
+        .checked_mul(ln_z)?
+        .checked_sub(z)?
+        .checked_add(C1 * LN_SQRT_PI2)?
+        .checked_add(sum)?
+        .checked_sub(shift)
+}
+
+/// ln(sin(πz)) = -ln(2) + iπ(1/2 - z) + ln(1 - e^(2πiz)), continuous for im(z) >= 0
+#[inline]
+fn ln_sin_pi_upper(
+    value: Complex,
+    terms: usize
+) -> Result<Complex, Error> {
+    // e^(2πiz) vanishes below Decimal precision for large im(z)
+    let exp = c_exp(Complex::new(-value.im(), value.re()).checked_scale(PI2)?, terms)?;
+    let ln = c_ln(&mut C1.checked_sub(exp)?, terms)?;
+    let linear = Complex::new(value.im(), D1DIV2 - value.re()).checked_scale(PI)?;
+    linear.checked_sub(C1 * LN_2)?.checked_add(ln)
+}
+
+//##########################################################################################################################
+
+/// ln(Γ(z)), the principal branch continuous off the negative real axis
+/// ln(Γ(z)) = ln(π) - ln(sin(πz)) - ln(Γ(1 - z)) for re(z) < 1/2
+pub fn c_lgamma(
+    value: Complex,
+    terms: usize
+) -> Result<Complex, Error> {
+    let ctx = |e: Error| e.context("c_lgamma", value);
+    // On the real axis, with im = π * floor(x) between the poles of x < 0
+    if value.im().is_zero() {
+        let re = d_lgamma(value.re(), terms).map_err(ctx)?;
+        let im = if value.re() < D0 {PI * value.re().floor()} else {D0};
+        return Ok(Complex::new(re, im))
+    };
+    if value.re() >= D1DIV2 { return lgamma_stirling(value, terms).map_err(ctx) };
+    // ln(Γ(conj(z))) = conj(ln(Γ(z)))
+    let lower = value.im() < D0;
+    let upper = if lower {value.conj()} else {value};
+    let ln_sin = ln_sin_pi_upper(upper, terms).map_err(ctx)?;
+    let ln_gamma = lgamma_stirling(C1.checked_sub(upper).map_err(ctx)?, terms).map_err(ctx)?;
+    let res = (C1 * LN_PI).checked_sub(ln_sin).and_then(|r| r.checked_sub(ln_gamma)).map_err(ctx)?;
+    Ok(if lower {res.conj()} else {res})
+}
+
+/// Γ(z) = e^(ln(Γ(z))), with Γ(x) = (x - 1)! for positive integers
+pub fn c_gamma(
+    value: Complex,
+    terms: usize
+) -> Result<Complex, Error> {
+    let ctx = |e: Error| e.context("c_gamma", value);
+    if value.im().is_zero() { return Ok(C1 * d_gamma(value.re(), terms).map_err(ctx)?) };
+    c_exp(c_lgamma(value, terms).map_err(ctx)?, terms).map_err(ctx)
+}
+
+//##########################################################################################################################
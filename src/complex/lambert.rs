@@ -0,0 +1,173 @@
+
+// Imports
+use rust_decimal::prelude::*;
+use rust_decimal_macros::dec;
+
+// Modules
+use crate::constants::{ E, PI2, D1DIVE };
+use crate::error::{ Error, ErrorKind };
+
+use crate::special::lambert::{ d_lambert_w0, d_lambert_wm1, D11DIV72, HALLEY_ITER_UPPER_BD, TOLERANCE };
+
+use crate::complex::types::{ Complex };
+use crate::complex::euler::{ c_exp, c_ln };
+
+//##########################################################################################################################
+
+// Constants
+const D0: Decimal = Decimal::ZERO;
+const D1: Decimal = Decimal::ONE;
+const D2: Decimal = Decimal::TWO;
+const D3: Decimal = dec!(3);
+const D3DIV2: Decimal = dec!(1.5);
+
+const C1: Complex = Complex::ONE;
+const C2: Complex = Complex::TWO;
+
+// Below this bound of |e * z + 1|^2 W_k(z) is seeded by the series around the branch point -1/e
+const BRANCH_RADIUS_SQR: Decimal = dec!(0.1);
+
+// Below this bound of |z|^2 W_0(z) is seeded by its Maclaurin series
+const SERIES_RADIUS_SQR: Decimal = dec!(0.0625);
+
+//##########################################################################################################################
+
+/// max(|re(z)|, |im(z)|)
+#[inline]
+fn c_norm(
+    value: Complex
+) -> Decimal {
+    value.re().abs().max(value.im().abs())
+}
+
+/// W(z) = -1 + p - p^2/3 + 11/72 * p^3, with p = ±sqrt(2 * (e * z + 1)) for z near -1/e
+#[inline]
+fn seed_branch(
+    value: Complex,
+    sign: Decimal,
+    terms: usize
+) -> Result<Complex, Error> {
+    let sqr = value.checked_scale(E)?.checked_add(C1)?.checked_scale(D2)?;
+    let p = c_exp(c_ln(&mut sqr.clone(), terms)?.checked_unscale(D2)?, terms)?.checked_scale(sign)?;
+    let p2 = p.checked_mul(p)?;
+    let p3 = p2.checked_mul(p)?;
+    p.checked_sub(C1)?
+        .checked_sub(p2.checked_unscale(D3)?)?
+        .checked_add(p3.checked_scale(D11DIV72)?)
+}
+
+/// W_k(z) = L1 - L2 + L2/L1, with L1 = ln(z) + 2πik and L2 = ln(L1), for large |W_k(z)|
+#[inline]
+fn seed_asymptotic(
+    value: Complex,
+    branch: i64,
+    terms: usize
+) -> Result<Complex, Error> {
+    let l1 = c_ln(&mut value.clone(), terms)?.checked_add(Complex::new(D0, PI2 * Decimal::from(branch)))?;
+    let l2 = c_ln(&mut l1.clone(), terms)?;
+    l1.checked_sub(l2)?.checked_add(l2.checked_div(l1)?)
+}
+
+/// Halley's method from a seed, stopping at the precision of the steps
+#[inline]
+fn halley<F>(
+    seed: Complex,
+    mut step: F
+) -> Result<Complex, Error>
+where F: FnMut(Complex) -> Result<Complex, Error> {
+    // Set Variables
+    let mut res = seed;
+    let mut last = Decimal::MAX;
+    for _ in 0..HALLEY_ITER_UPPER_BD {
+        let delta = step(res)?;
+        // Steps stop shrinking at the precision of e^w and ln(w)
+        if c_norm(delta) >= last { return Ok(res) };
+        res = res.checked_sub(delta)?;
+        last = c_norm(delta);
+        if last < TOLERANCE { return Ok(res) };
+    };
+    Err(ErrorKind::NotConverged.into())
+}
+
+/// f(w) = w * e^w - z
+/// w' = w - f / (e^w * (w + 1) - (w + 2) * f / (2w + 2))
+#[inline]
+fn halley_direct(
+    value: Complex,
+    seed: Complex,
+    terms: usize
+) -> Result<Complex, Error> {
+    halley(seed, |w| {
+        let exp = c_exp(w, terms)?;
+        let f = w.checked_mul(exp)?.checked_sub(value)?;
+        let w1 = w.checked_add(C1)?;
+        let corr = w.checked_add(C2)?.checked_mul(f)?.checked_div(w1.checked_scale(D2)?)?;
+        f.checked_div(exp.checked_mul(w1)?.checked_sub(corr)?)
+    })
+}
+
+/// g(w) = w + ln(w) - ln(z) - 2πik, with g' = 1 + 1/w and g'' = -1/w^2
+/// w' = w - 2 * g * g' / (2 * g'^2 - g * g'')
+#[inline]
+fn halley_log(
+    value: Complex,
+    seed: Complex,
+    branch: i64,
+    terms: usize
+) -> Result<Complex, Error> {
+    let ln_z = c_ln(&mut value.clone(), terms)?.checked_add(Complex::new(D0, PI2 * Decimal::from(branch)))?;
+    halley(seed, |w| {
+        let g = w.checked_add(c_ln(&mut w.clone(), terms)?)?.checked_sub(ln_z)?;
+        let inv = w.checked_inv()?;
+        let g1 = C1.checked_add(inv)?;
+        let g2 = -inv.checked_mul(inv)?;
+        let num = g.checked_mul(g1)?.checked_scale(D2)?;
+        num.checked_div(g1.checked_mul(g1)?.checked_scale(D2)?.checked_sub(g.checked_mul(g2)?)?)
+    })
+}
+
+//##########################################################################################################################
+
+/// W_k(z), the solution of w * e^w = z on branch k
+/// W_k(z) = W_0(x) or W_-1(x) on the real intervals where they are real
+pub fn c_lambert_w(
+    value: Complex,
+    branch: i64,
+    terms: usize
+) -> Result<Complex, Error> {
+    let ctx = |e: Error| e.context("c_lambert_w", format!("{}, {}", value, branch));
+    let (re, im) = (value.re(), value.im());
+    if im.is_zero() {
+        if (branch ==  0) && (re >= -D1DIVE) { return Ok(C1 * d_lambert_w0(re, terms).map_err(ctx)?) };
+        if (branch == -1) && (re >= -D1DIVE) && (re <= D0) { return Ok(C1 * d_lambert_wm1(re, terms).map_err(ctx)?) };
+        if (branch !=  0) && re.is_zero() { Err(ctx(ErrorKind::Pole.into()))? };
+    };
+    // W_0, W_-1 from above and W_1 from below meet at the branch point
+    let sign =
+             if branch == 0 { Some(D1) }
+        else if ((branch == -1) && (im >= D0)) || ((branch == 1) && (im < D0)) { Some(-D1) }
+        else { None };
+    let shift = value.checked_scale(E).and_then(|z| z.checked_add(C1)).map_err(ctx)?;
+    if let Some(sign) = sign {
+        if shift.checked_radius_sqr().map_err(ctx)? < BRANCH_RADIUS_SQR {
+            let seed = seed_branch(value, sign, terms).map_err(ctx)?;
+            return halley_direct(value, seed, terms).map_err(ctx)
+        };
+    };
+    if branch == 0 {
+        let seed = if value.checked_radius_sqr().map_err(ctx)? < SERIES_RADIUS_SQR {
+            // W_0(z) = z - z^2 + 3/2 * z^3 - ...
+            let sqr = value.checked_mul(value).map_err(ctx)?;
+            let cube = sqr.checked_mul(value).map_err(ctx)?.checked_scale(D3DIV2).map_err(ctx)?;
+            value.checked_sub(sqr).and_then(|s| s.checked_add(cube)).map_err(ctx)?
+        }
+        else { seed_asymptotic(value, 0, terms).map_err(ctx)? };
+        halley_direct(value, seed, terms).map_err(ctx)
+    }
+    else {
+        let seed = seed_asymptotic(value, branch, terms).map_err(ctx)?;
+        halley_log(value, seed, branch, terms).map_err(ctx)
+    }
+}
+
+//##########################################################################################################################
@@ -3,6 +3,8 @@ pub mod types;
 pub mod basic;
 pub mod euler;
 pub mod trigonometry;
+pub mod gamma;
+pub mod lambert;
 pub mod riemann;
 
 #[cfg(test)]
@@ -1,46 +1,664 @@
-
 // Imports
 use rust_decimal::prelude::*;
+use rust_decimal_macros::dec;
 use rayon::prelude::*;
 
 // Modules
-use crate::error::Error;
+use crate::constants::{ PI2, PIDIV2, LN_2, LN_PI };
+use crate::error::{ Error, ErrorKind };
+
+use crate::basic::{ dec };
+use crate::euler::{ d_ln };
+use crate::trigonometry::{ d_cos, d_sin };
+use crate::number_theory::arithmetic::{ gcd, totient };
+use crate::number_theory::modular::{ jacobi, primitive_root };
+use crate::primes::riemann::{ mobius };
+use crate::special::gamma::{ STIRLING };
 
 use crate::complex::types::{ Complex };
-use crate::complex::basic::{ cpx, cc_pow };
+use crate::complex::basic::{ cc_pow };
+use crate::complex::euler::{ c_exp, c_ln };
+use crate::complex::trigonometry::{ c_sin };
+use crate::complex::gamma::{ lgamma_stirling, c_gamma };
 
 //##########################################################################################################################
 
 // Constants
+const D0: Decimal = Decimal::ZERO;
 const D1: Decimal = Decimal::ONE;
+const D2: Decimal = Decimal::TWO;
+const D3: Decimal = dec!(3);
+const D4: Decimal = dec!(4);
+const D1DIV2: Decimal = dec!(0.5);
+const D1DIV4: Decimal = dec!(0.25);
 
 const C0: Complex = Complex::ZERO;
+const C1: Complex = Complex::ONE;
+
+// Largest relative Borwein term before rescaling
+const WEIGHT_UPPER_BD: Decimal = dec!(1_000_000_000_000_000_000);
+
+// Below this squared modulus of 1 - 2^(1-s), Z(s) is taken from the Euler-Maclaurin sum instead of η(s)
+const BORWEIN_DENOM_LOWER_BD: Decimal = dec!(0.000001);
+
+// Largest modulus of a Dirichlet character
+const CHARACTER_UPPER_BD: u64 = 1 << 20;
+
+// Bits below Decimal precision, the Möbius sum of P(s) stops once 2^(-n * re(s)) drops under them
+const PRIME_ZETA_BITS: Decimal = dec!(94);
+
+// Below this squared modulus of z, Li_s(z) is summed directly, and above it by the series in ln(z)
+const POLYLOG_DIRECT_UPPER_BD: Decimal = dec!(0.25);
+
+// Largest number of terms in the power series of Li_s(z)
+const POLYLOG_ITER_UPPER_BD: usize = 1_000;
+
+// Largest number of terms in the series of Li_s(z) in ln(z), past which Z(s - k) leaves a Decimal
+const POLYLOG_LOG_ITER_UPPER_BD: usize = 50;
+
+// Relative precision where the series of Li_s(z) stop
+const POLYLOG_TOLERANCE: Decimal = dec!(0.0000000000000000000000000001);
+
+// Gram points evaluated per parallel batch while scanning for zeros
+const GRAM_BATCH: i64 = 16;
+
+// Halvings of a Gram block before giving up on Rosser's rule
+const GRAM_BLOCK_DEPTH: usize = 8;
+
+//##########################################################################################################################
+
+/// x^(-s) = e^(-s * ln(x)), which c_exp flushes to zero below Decimal precision
+#[inline]
+fn pow_neg(
+    value: Decimal,
+    power: Complex,
+    terms: usize
+) -> Result<Complex, Error> {
+    cc_pow(&mut Complex::new(value, D0), -power, terms)
+}
 
 //##########################################################################################################################
 
-/// Z(x) = 1/(1^x) + 1/(2^x) + 1/(3^x) + 1/(4^x) + 1/(5^x) ...
-/// Z(x) = sum(n=1; n^(-x))
-fn zeta_series(
+/// Borwein weights w_k = (d_n - d_k) / d_n for k in 0..n, with
+/// d_k = n * sum(i=0; k; (n + i - 1)! * 4^i / ((n - i)! * (2i)!))
+#[inline]
+fn borwein_weights(
+    count: usize
+) -> Result<Vec<Decimal>, Error> {
+    // Terms of d_n relative to the last one, by backward recurrence of
+    // t_i / t_(i-1) = 4 * (n + i - 1) * (n - i + 1) / (2i * (2i - 1))
+    let mut rel: Vec<Decimal> = vec![D1; count + 1];
+    for i in (1..=count).rev() {
+        let num = D4 * dec(count + i - 1) * dec(count - i + 1);
+        let den = dec(2 * i) * dec((2 * i) - 1);
+        rel[i - 1] = (rel[i] / num).checked_mul(den).ok_or(ErrorKind::MultiplyOverflow)?;
+        // Rescale before overflow, the smallest terms are lost below precision
+        if rel[i - 1] > WEIGHT_UPPER_BD {
+            for r in rel[(i - 1)..].iter_mut() { *r /= WEIGHT_UPPER_BD; };
+        };
+    };
+    // w_k = sum(i=k+1; n; t_i) / sum(i=0; n; t_i)
+    let mut tail: Vec<Decimal> = vec![D0; count + 1];
+    for i in (0..count).rev() {
+        tail[i] = tail[i + 1].checked_add(rel[i + 1]).ok_or(ErrorKind::AddOverflow)?;
+    };
+    let total = tail[0] + rel[0];
+    Ok(tail[..count].iter().map(|t| t / total).collect())
+}
+
+/// η(s) = sum(k=0; n-1; (-1)^k * w_k / (k + 1)^s), Borwein's acceleration of the alternating series
+#[inline]
+fn eta_borwein(
     value: Complex,
     zeta_terms: usize,
     terms: usize
 ) -> Result<Complex, Error> {
-    // Iterate over Zeta Series
-    (1..=zeta_terms).into_par_iter()
-        .map(|n| Ok(
-            D1 / cc_pow(&mut cpx(n), value, terms)?
-        ))
-        .reduce(|| Ok(C0), |u, d| Ok(u? + d?))
+    let weights = borwein_weights(zeta_terms)?;
+    // Iterate over Eta Series
+    weights.into_par_iter().enumerate()
+        .map(|(k, w)| {
+            let term = pow_neg(dec(k + 1), value, terms)?.checked_scale(w)?;
+            Ok(if let 0=k%2 {term} else {-term})
+        })
+        .reduce(|| Ok(C0), |u, d| u?.checked_add(d?))
 }
 
-/// Riemann Zeta Function
+/// Z(s) = η(s) / (1 - 2^(1-s))
+/// Near the zeros s = 1 + 2πik/ln(2) of the denominator, Z(s) = Z(s, 1) by Euler-Maclaurin
+#[inline]
+fn zeta_borwein(
+    value: Complex,
+    zeta_terms: usize,
+    terms: usize
+) -> Result<Complex, Error> {
+    let pow2 = c_exp(C1.checked_sub(value)?.checked_scale(LN_2)?, terms)?;
+    let den = C1.checked_sub(pow2)?;
+    if den.checked_radius_sqr()? < BORWEIN_DENOM_LOWER_BD {
+        return hurwitz_euler_maclaurin(value, D1, zeta_terms, terms)
+    };
+    eta_borwein(value, zeta_terms, terms)?.checked_div(den)
+}
+
+//##########################################################################################################################
+
+/// Z(s) = 2^s * π^(s-1) * sin(πs/2) * Γ(1 - s) * Z(1 - s)
+#[inline]
+fn zeta_reflect(
+    value: Complex,
+    zeta_terms: usize,
+    terms: usize
+) -> Result<Complex, Error> {
+    let reflected = C1.checked_sub(value)?;
+    // 2^s * π^(s-1) * Γ(1 - s) as a single exponential
+    let exponent = value.checked_scale(LN_2)?
+        .checked_add(value.checked_sub(C1)?.checked_scale(LN_PI)?)?
+        .checked_add(lgamma_stirling(reflected, terms)?)?;
+    let sin = c_sin(value.checked_scale(PIDIV2)?, terms)?;
+    c_exp(exponent, terms)?
+        .checked_mul(sin)?
+        .checked_mul(zeta_borwein(reflected, zeta_terms, terms)?)
+}
+
+//##########################################################################################################################
+
+/// Riemann Zeta Function, for every s != 1
+/// Z(s) = sum(n=1; n^(-s)), continued to the complex plane
+/// About 40 `zeta_terms` give full precision near the real axis, more are needed as |im(s)| grows
 pub fn zeta(
     value: Complex,
     zeta_terms: usize,
     terms: usize
 ) -> Result<Complex, Error> {
-    if value.re() <= D1 { Err(Error::InputOutOfRange)? };
-    zeta_series(value, zeta_terms, terms)
+    let ctx = |e: Error| e.context("zeta", value);
+    if value == C1 { Err(ctx(ErrorKind::Pole.into()))? };
+    if zeta_terms == 0 { Err(ctx(ErrorKind::InputOutOfRange.into()))? };
+    if value.re() < D0 { zeta_reflect(value, zeta_terms, terms).map_err(ctx) }
+    else { zeta_borwein(value, zeta_terms, terms).map_err(ctx) }
+}
+
+//##########################################################################################################################
+
+/// Prime Zeta Function, for re(s) > 1
+/// P(s) = sum(p; p^(-s)) = sum(n=1; μ(n)/n * ln(Z(ns)))
+pub fn prime_zeta(
+    value: Complex,
+    zeta_terms: usize,
+    terms: usize
+) -> Result<Complex, Error> {
+    let ctx = |e: Error| e.context("prime_zeta", value);
+    if value.re() <= D1 { Err(ctx(ErrorKind::InputOutOfRange.into()))? };
+    // ln(Z(ns)) ≈ 2^(-ns) once ns is large
+    let count = (PRIME_ZETA_BITS / value.re()).ceil().to_usize().ok_or(ctx(ErrorKind::InputOutOfRange.into()))?;
+    (1..=count).into_par_iter()
+        .map(|n| {
+            let mu = mobius(n)?;
+            if mu.is_zero() { return Ok(C0) };
+            let mut zeta = zeta(value.checked_scale(dec(n))?, zeta_terms, terms)?;
+            c_ln(&mut zeta, terms)?.checked_scale(mu / dec(n))
+        })
+        .reduce(|| Ok(C0), |u, d| u?.checked_add(d?))
+        .map_err(ctx)
+}
+
+//##########################################################################################################################
+
+/// θ(t) = arg(Γ(1/4 + it/2)) - (t/2) * ln(π)
+pub fn riemann_siegel_theta(
+    value: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    let ctx = |e: Error| e.context("riemann_siegel_theta", value);
+    let z = Complex::new(D1DIV4, value / D2);
+    let ln_gamma = lgamma_stirling(z, terms).map_err(ctx)?;
+    let half_ln_pi = (value / D2).checked_mul(LN_PI).ok_or(ctx(ErrorKind::MultiplyOverflow.into()))?;
+    ln_gamma.im().checked_sub(half_ln_pi).ok_or(ctx(ErrorKind::AddOverflow.into()))
+}
+
+#[inline]
+fn riemann_siegel_z_helper(
+    value: Decimal,
+    zeta_terms: usize,
+    terms: usize
+) -> Result<Decimal, Error> {
+    let theta = riemann_siegel_theta(value, terms)?;
+    let zeta = zeta(Complex::new(D1DIV2, value), zeta_terms, terms)?;
+    // Z(t) is real, so only re(e^(iθ) * ζ) is needed
+    let re = d_cos(theta, terms)?.checked_mul(zeta.re()).ok_or(ErrorKind::MultiplyOverflow)?;
+    let im = d_sin(theta, terms)?.checked_mul(zeta.im()).ok_or(ErrorKind::MultiplyOverflow)?;
+    Ok(re.checked_sub(im).ok_or(ErrorKind::AddOverflow)?)
+}
+
+/// Z(t) = e^(iθ(t)) * ζ(1/2 + it), real valued with |Z(t)| = |ζ(1/2 + it)|
+pub fn riemann_siegel_z(
+    value: Decimal,
+    zeta_terms: usize,
+    terms: usize
+) -> Result<Decimal, Error> {
+    riemann_siegel_z_helper(value, zeta_terms, terms)
+        .map_err(|e| e.context("riemann_siegel_z", format!("{}, {}", value, zeta_terms)))
+}
+
+//##########################################################################################################################
+
+/// Gram point g_n with θ(g_n) = nπ, for n >= -1
+/// Newton's method on θ(t) ~ (t/2) * ln(t/2π) - t/2 - π/8 + 1/48t, from the right of the root
+#[inline]
+fn gram_point(
+    value: i64
+) -> Result<Decimal, Error> {
+    use std::f64::consts::PI;
+    let target = (value as f64) * PI;
+    let mut t = 2.0 * PI * ((value as f64) + 10.0);
+    for _ in 0..64 {
+        let theta = ((t / 2.0) * (t / (2.0 * PI)).ln()) - (t / 2.0) - (PI / 8.0) + (1.0 / (48.0 * t));
+        let step = (theta - target) / ((t / (2.0 * PI)).ln() / 2.0);
+        t -= step;
+        if step.abs() < 1e-9 {break};
+    };
+    Ok(Decimal::from_f64(t).ok_or(ErrorKind::OptionInvalid)?)
+}
+
+/// Brackets [a, b] with a sign change of Z inside a Gram block holding `expected` zeros by Rosser's rule,
+/// halving the sample spacing until every zero is found
+fn gram_block_brackets(
+    points: Vec<(Decimal, Decimal)>,
+    expected: usize,
+    zeta_terms: usize,
+    terms: usize
+) -> Result<Vec<(Decimal, Decimal, Decimal, Decimal)>, Error> {
+    let mut points = points;
+    for _ in 0..GRAM_BLOCK_DEPTH {
+        let brackets: Vec<(Decimal, Decimal, Decimal, Decimal)> = points.windows(2)
+            .filter(|w| w[0].1.is_sign_negative() != w[1].1.is_sign_negative())
+            .map(|w| (w[0].0, w[1].0, w[0].1, w[1].1))
+            .collect();
+        if brackets.len() >= expected { return Ok(brackets) };
+        // Sample the midpoints in parallel
+        let mids = points.par_windows(2)
+            .map(|w| {
+                let mid = (w[0].0 + w[1].0) / D2;
+                Ok((mid, riemann_siegel_z_helper(mid, zeta_terms, terms)?))
+            })
+            .collect::<Result<Vec<(Decimal, Decimal)>, Error>>()?;
+        points = points.into_iter()
+            .zip(mids.into_iter().map(Some).chain([None]))
+            .flat_map(|(p, m)| [Some(p), m])
+            .flatten()
+            .collect();
+    };
+    Err(ErrorKind::NotConverged.into())
+}
+
+/// Root of Z in [a, b] by Brent's method, to `digits` decimal places
+fn brent_zero(
+    bracket: (Decimal, Decimal, Decimal, Decimal),
+    digits: u32,
+    zeta_terms: usize,
+    terms: usize
+) -> Result<Decimal, Error> {
+    let tolerance = Decimal::new(1, digits);
+    let (mut a, mut b, mut fa, mut fb) = bracket;
+    if fa.abs() < fb.abs() { (a, b, fa, fb) = (b, a, fb, fa); };
+    // Set Variables
+    let (mut c, mut fc) = (a, fa);
+    let mut d = c;
+    let mut bisected = true;
+    while (fb != D0) && ((b - a).abs() > tolerance) {
+        // Inverse quadratic interpolation, or the secant step
+        let interp = if (fa != fc) && (fb != fc) {
+            let ta = (a * fb * fc).checked_div((fa - fb) * (fa - fc));
+            let tb = (b * fa * fc).checked_div((fb - fa) * (fb - fc));
+            let tc = (c * fa * fb).checked_div((fc - fa) * (fc - fb));
+            ta.zip(tb).zip(tc).map(|((ta, tb), tc)| ta + tb + tc)
+        }
+        else { (fb * (b - a)).checked_div(fb - fa).map(|t| b - t) };
+        // Fall back to bisection unless the step stays well inside the bracket
+        let last = if bisected {(b - c).abs()} else {(c - d).abs()};
+        let quarter = ((D3 * a) + b) / D4;
+        let s = match interp {
+            Some(s) if (s - quarter).is_sign_negative() != (s - b).is_sign_negative()
+                && ((s - b).abs() < (last / D2)) && (last >= tolerance) => { bisected = false; s },
+            _ => { bisected = true; (a + b) / D2 },
+        };
+        let fs = riemann_siegel_z_helper(s, zeta_terms, terms)?;
+        (d, c, fc) = (c, b, fb);
+        if fa.is_sign_negative() != fs.is_sign_negative() { (b, fb) = (s, fs); }
+        else { (a, fa) = (s, fs); };
+        if fa.abs() < fb.abs() { (a, b, fa, fb) = (b, a, fb, fa); };
+    };
+    Ok(b.round_dp(digits))
+}
+
+/// Imaginary parts of the first `count` nontrivial zeros 1/2 + it of ζ, to `digits` decimal places
+/// Scans Z(t) over Gram points in parallel, and refines every sign change by Brent's method
+pub fn zeta_zeros(
+    count: usize,
+    digits: u32,
+    zeta_terms: usize,
+    terms: usize
+) -> Result<Vec<Decimal>, Error> {
+    let ctx = |e: Error| e.context("zeta_zeros", format!("{}, {}", count, digits));
+    if digits > 28 { Err(ctx(ErrorKind::InputOutOfRange.into()))? };
+    let z = |t: Decimal| riemann_siegel_z_helper(t, zeta_terms, terms);
+    // Set Variables
+    let mut brackets: Vec<(Decimal, Decimal, Decimal, Decimal)> = Vec::new();
+    let first = gram_point(-1).map_err(ctx)?;
+    let mut block: Vec<(Decimal, Decimal)> = vec![(first, z(first).map_err(ctx)?)];
+    let mut good: i64 = -1;
+    let mut n: i64 = -1;
+    // Scan Gram blocks, bounded by good Gram points with (-1)^n * Z(g_n) > 0
+    while brackets.len() < count {
+        let batch = ((n + 1)..=(n + GRAM_BATCH)).into_par_iter()
+            .map(|k| {
+                let g = gram_point(k)?;
+                Ok((k, g, z(g)?))
+            })
+            .collect::<Result<Vec<(i64, Decimal, Decimal)>, Error>>()
+            .map_err(ctx)?;
+        for (k, g, zg) in batch {
+            block.push((g, zg));
+            if zg.is_sign_negative() == (k % 2 != 0) {
+                let expected = (k - good) as usize;
+                brackets.extend(gram_block_brackets(block, expected, zeta_terms, terms).map_err(ctx)?);
+                block = vec![(g, zg)];
+                good = k;
+            };
+        };
+        n += GRAM_BATCH;
+    };
+    brackets.truncate(count);
+    // Refine zeros in parallel
+    brackets.into_par_iter()
+        .map(|b| brent_zero(b, digits, zeta_terms, terms))
+        .collect::<Result<Vec<Decimal>, Error>>()
+        .map_err(ctx)
+}
+
+//##########################################################################################################################
+
+/// η(s) = sum(n=1; (-1)^(n-1) * n^(-s)) = (1 - 2^(1-s)) * Z(s)
+pub fn eta(
+    value: Complex,
+    zeta_terms: usize,
+    terms: usize
+) -> Result<Complex, Error> {
+    let ctx = |e: Error| e.context("eta", value);
+    if zeta_terms == 0 { Err(ctx(ErrorKind::InputOutOfRange.into()))? };
+    if value.re() < D0 {
+        let pow2 = c_exp(C1.checked_sub(value).map_err(ctx)?.checked_scale(LN_2).map_err(ctx)?, terms).map_err(ctx)?;
+        let zeta = zeta_reflect(value, zeta_terms, terms).map_err(ctx)?;
+        C1.checked_sub(pow2).and_then(|f| f.checked_mul(zeta)).map_err(ctx)
+    }
+    else { eta_borwein(value, zeta_terms, terms).map_err(ctx) }
+}
+
+//##########################################################################################################################
+
+/// Z(s, a) = sum(k=0; N-1; (k + a)^(-s)) + x^(1-s) / (s - 1) + x^(-s) / 2
+///         + sum(j=1; B_2j / (2j)! * s(s + 1)...(s + 2j - 2) * x^(-s-2j+1)), with x = N + a
+/// At s = 1 the pole 1 / (s - 1) is dropped, leaving -ln(x) for x^(1-s) / (s - 1) and the limit -ψ(a)
+#[inline]
+fn hurwitz_euler_maclaurin(
+    value: Complex,
+    shift: Decimal,
+    zeta_terms: usize,
+    terms: usize
+) -> Result<Complex, Error> {
+    let head = (0..zeta_terms).into_par_iter()
+        .map(|k| pow_neg(dec(k) + shift, value, terms))
+        .reduce(|| Ok(C0), |u, d| u?.checked_add(d?))?;
+    let x = shift.checked_add(dec(zeta_terms)).ok_or(ErrorKind::AddOverflow)?;
+    let x_pow = pow_neg(x, value, terms)?;
+    let integral =
+        if value == C1 { C1 * -d_ln(x, terms)? }
+        else { x_pow.checked_scale(x)?.checked_div(value.checked_sub(C1)?)? };
+    let half = x_pow.checked_unscale(D2)?;
+    // B_2j / (2j)! * s(s + 1)...(s + 2j - 2) = B_2j / (2j * (2j - 1)) * s(s + 1)...(s + 2j - 2) / (2j - 2)!
+    let inv_sqr = D1.checked_div(x * x).ok_or(ErrorKind::DivisionByZero)?;
+    let mut rising = value;
+    let mut pow = x_pow.checked_unscale(x)?;
+    let mut sum = C0;
+    for (j, c) in STIRLING.iter().enumerate().map(|(j, c)| (j + 1, c)) {
+        if j > 1 {
+            let k = (2 * j) - 2;
+            rising = rising
+                .checked_mul(value.checked_add(C1 * dec(k - 1))?)?
+                .checked_mul(value.checked_add(C1 * dec(k))?)?
+                .checked_unscale(dec(k - 1) * dec(k))?;
+            pow = pow.checked_scale(inv_sqr)?;
+        };
+        sum = sum.checked_add(rising.checked_mul(pow)?.checked_scale(*c)?)?;
+    };
+    head.checked_add(integral)?.checked_add(half)?.checked_add(sum)
+}
+
+/// Hurwitz Zeta Function, for every s != 1 and a > 0
+/// Z(s, a) = sum(k=0; (k + a)^(-s))
+pub fn hurwitz_zeta(
+    value: Complex,
+    shift: Decimal,
+    zeta_terms: usize,
+    terms: usize
+) -> Result<Complex, Error> {
+    let ctx = |e: Error| e.context("hurwitz_zeta", format!("{}, {}", value, shift));
+    if value == C1 { Err(ctx(ErrorKind::Pole.into()))? };
+    if shift <= D0 { Err(ctx(ErrorKind::InputOutOfRange.into()))? };
+    hurwitz_euler_maclaurin(value, shift, zeta_terms, terms).map_err(ctx)
+}
+
+//##########################################################################################################################
+
+/// Largest of |re(z)| and |im(z)|
+#[inline]
+fn c_norm(
+    value: Complex
+) -> Decimal {
+    value.re().abs().max(value.im().abs())
+}
+
+/// Li_s(z) = sum(k=1; z^k / k^s), until the terms fall below precision
+#[inline]
+fn polylog_direct(
+    value: Complex,
+    arg: Complex,
+    terms: usize
+) -> Result<Complex, Error> {
+    // Set Variables
+    let mut pow = C1;
+    let mut sum = C0;
+    for k in 1..POLYLOG_ITER_UPPER_BD {
+        pow = pow.checked_mul(arg)?;
+        let term = pow.checked_mul(pow_neg(dec(k), value, terms)?)?;
+        sum = sum.checked_add(term)?;
+        if c_norm(term) <= c_norm(sum) * POLYLOG_TOLERANCE { return Ok(sum) };
+    };
+    Err(ErrorKind::NotConverged.into())
+}
+
+/// Li_s(z) = Γ(1 - s) * (-μ)^(s-1) + sum(k=0; Z(s - k) * μ^k / k!), with μ = ln(z) and |μ| < 2π
+/// Li_n(z) = μ^(n-1) / (n-1)! * (H_(n-1) - ln(-μ)) + sum(k=0; k != n-1; Z(n - k) * μ^k / k!), for positive integers n
+#[inline]
+fn polylog_log(
+    value: Complex,
+    arg: Complex,
+    zeta_terms: usize,
+    terms: usize
+) -> Result<Complex, Error> {
+    let mu = c_ln(&mut arg.clone(), terms)?;
+    let order = if value.im().is_zero() && value.re().fract().is_zero() && (value.re() >= D1) {value.re().to_usize()} else {None};
+    // Γ(1 - s) and Z(s - n + 1) share a pole at positive integers, which cancels into the harmonic number
+    let head = match order {
+        Some(n) => {
+            let mut coef = C1;
+            let mut harmonic = D0;
+            for j in 1..n {
+                coef = coef.checked_mul(mu)?.checked_unscale(dec(j))?;
+                harmonic += D1 / dec(j);
+            };
+            coef.checked_mul((C1 * harmonic).checked_sub(c_ln(&mut -mu, terms)?)?)?
+        },
+        None => {
+            let gamma = c_gamma(C1.checked_sub(value)?, terms)?;
+            gamma.checked_mul(cc_pow(&mut -mu, value.checked_sub(C1)?, terms)?)?
+        },
+    };
+    // Set Variables
+    let mut coef = C1;
+    let mut sum = head;
+    let mut small = false;
+    for k in 0..POLYLOG_LOG_ITER_UPPER_BD {
+        if k > 0 { coef = coef.checked_mul(mu)?.checked_unscale(dec(k))?; };
+        if order == Some(k + 1) { continue };
+        let term = zeta(value.checked_sub(C1 * dec(k))?, zeta_terms, terms)?.checked_mul(coef)?;
+        sum = sum.checked_add(term)?;
+        // Z(s - k) vanishes at every other k for integers s, so two small terms in a row end the series
+        let below = c_norm(term) <= c_norm(sum) * POLYLOG_TOLERANCE;
+        if below && small { return Ok(sum) };
+        small = below;
+    };
+    Err(ErrorKind::NotConverged.into())
+}
+
+/// Li_s(z) for |z| < 1, directly for small |z| and by the series in ln(z) otherwise
+#[inline]
+fn polylog_helper(
+    value: Complex,
+    arg: Complex,
+    zeta_terms: usize,
+    terms: usize
+) -> Result<Complex, Error> {
+    if arg.checked_radius_sqr()? <= POLYLOG_DIRECT_UPPER_BD { return polylog_direct(value, arg, terms) };
+    if arg.re() >= D0 { return polylog_log(value, arg, zeta_terms, terms) };
+    // Li_s(z) = 2^(1-s) * Li_s(z^2) - Li_s(-z), which keeps |ln(z)| small in the left half-plane
+    let pow2 = c_exp(C1.checked_sub(value)?.checked_scale(LN_2)?, terms)?;
+    let sqr = polylog_helper(value, arg.checked_mul(arg)?, zeta_terms, terms)?;
+    pow2.checked_mul(sqr)?.checked_sub(polylog_log(value, -arg, zeta_terms, terms)?)
+}
+
+/// Li_s(z) = sum(k=1; z^k / k^s), for |z| < 1 and z = ±1
+/// Li_s(1) = Z(s) and Li_s(-1) = -η(s)
+/// Away from z = 0 the values Z(s - k) take `zeta_terms`, about 40 give full precision
+pub fn polylog(
+    value: Complex,
+    arg: Complex,
+    zeta_terms: usize,
+    terms: usize
+) -> Result<Complex, Error> {
+    let ctx = |e: Error| e.context("polylog", format!("{}, {}", value, arg));
+    if arg == C1 { return zeta(value, zeta_terms, terms).map_err(ctx) };
+    if arg == -C1 { return Ok(-eta(value, zeta_terms, terms).map_err(ctx)?) };
+    if arg.checked_radius_sqr().map_err(ctx)? >= D1 { Err(ctx(ErrorKind::InputOutOfRange.into()))? };
+    polylog_helper(value, arg, zeta_terms, terms).map_err(ctx)
+}
+
+//##########################################################################################################################
+
+/// A Dirichlet character χ modulo q, by its values on 0..q
+#[derive(Clone, Debug)]
+pub struct DirichletCharacter {
+    /// Modulus q of the character
+    modulus: u64,
+    /// χ(n) for n in 0..q
+    values: Vec<Complex>
+}
+
+impl DirichletCharacter {
+    /// Create a character from its values on 0..q, with χ(1) = 1 and χ(n) = 0 exactly when gcd(n, q) > 1
+    #[inline]
+    pub fn new(modulus: u64, values: Vec<Complex>) -> Result<Self, Error> {
+        let err = |kind: ErrorKind| Error::from(kind).context("DirichletCharacter::new", modulus);
+        if (modulus == 0) || (modulus > CHARACTER_UPPER_BD) { Err(err(ErrorKind::InputOutOfRange))? };
+        if values.len() != (modulus as usize) { Err(err(ErrorKind::InputOutOfRange))? };
+        let coprime = |n: usize| gcd(n as u64, modulus) == 1;
+        if values.iter().enumerate().any(|(n, v)| v.is_zero() == coprime(n)) { Err(err(ErrorKind::DomainError))? };
+        if values[(1 % modulus) as usize] != C1 { Err(err(ErrorKind::DomainError))? };
+        Ok(Self { modulus, values })
+    }
+
+    /// Principal character, χ(n) = 1 when gcd(n, q) = 1
+    #[inline]
+    pub fn principal(modulus: u64) -> Result<Self, Error> {
+        if (modulus == 0) || (modulus > CHARACTER_UPPER_BD) {
+            Err(Error::from(ErrorKind::InputOutOfRange).context("DirichletCharacter::principal", modulus))?
+        };
+        let values = (0..modulus)
+            .map(|n| if gcd(n, modulus) == 1 {C1} else {C0})
+            .collect();
+        Ok(Self { modulus, values })
+    }
+
+    /// Real character χ(n) = (n/q) by the Jacobi symbol, for odd q
+    #[inline]
+    pub fn jacobi(modulus: u64) -> Result<Self, Error> {
+        let ctx = |e: Error| e.context("DirichletCharacter::jacobi", modulus);
+        if (modulus == 0) || (modulus > CHARACTER_UPPER_BD) { Err(ctx(ErrorKind::InputOutOfRange.into()))? };
+        let values = (0..modulus)
+            .map(|n| Ok(C1 * Decimal::from(jacobi(n, modulus)?)))
+            .collect::<Result<Vec<Complex>, Error>>()
+            .map_err(ctx)?;
+        Ok(Self { modulus, values })
+    }
+
+    /// Character of index k modulo q with a primitive root g, χ(g^j) = e^(2πi * jk / φ(q))
+    #[inline]
+    pub fn cyclic(modulus: u64, index: u64, terms: usize) -> Result<Self, Error> {
+        let ctx = |e: Error| e.context("DirichletCharacter::cyclic", format!("{}, {}", modulus, index));
+        if modulus > CHARACTER_UPPER_BD { Err(ctx(ErrorKind::InputOutOfRange.into()))? };
+        let g = primitive_root(modulus).map_err(ctx)?;
+        let phi = totient(modulus).map_err(ctx)?;
+        // Walk the powers of g, which cover every unit modulo q
+        let mut values: Vec<Complex> = vec![C0; modulus as usize];
+        let mut pow: u64 = 1 % modulus;
+        for j in 0..phi {
+            let angle = PI2 * (Decimal::from(((j as u128) * (index as u128)) % (phi as u128)) / Decimal::from(phi));
+            let re = d_cos(angle, terms).map_err(ctx)?;
+            let im = d_sin(angle, terms).map_err(ctx)?;
+            values[pow as usize] = Complex::new(re, im);
+            pow = (pow * g) % modulus;
+        };
+        Ok(Self { modulus, values })
+    }
+
+    /// Get modulus property
+    #[inline]
+    pub fn modulus(&self) -> u64 {
+        self.modulus
+    }
+
+    /// χ(n)
+    #[inline]
+    pub fn value(&self, n: u64) -> Complex {
+        self.values[(n % self.modulus) as usize]
+    }
+
+    /// Whether χ(n) = 1 for every n coprime to q
+    #[inline]
+    pub fn is_principal(&self) -> bool {
+        self.values.iter().all(|v| v.is_zero() || (*v == C1))
+    }
+}
+
+/// Dirichlet L-function, for every s != 1 when χ is principal
+/// L(s, χ) = sum(n=1; χ(n) * n^(-s)) = q^(-s) * sum(r=1; q; χ(r) * Z(s, r/q))
+/// L(1, χ) = -1/q * sum(r=1; q; χ(r) * ψ(r/q)), as sum(r=1; q; χ(r)) = 0 cancels the poles
+pub fn dirichlet_l(
+    value: Complex,
+    character: &DirichletCharacter,
+    zeta_terms: usize,
+    terms: usize
+) -> Result<Complex, Error> {
+    let q = character.modulus();
+    let ctx = |e: Error| e.context("dirichlet_l", format!("{}, {}", value, q));
+    if (value == C1) && character.is_principal() { Err(ctx(ErrorKind::Pole.into()))? };
+    let sum = (1..=q).into_par_iter()
+        .filter(|&r| !character.value(r).is_zero())
+        .map(|r| {
+            let shift = Decimal::from(r) / Decimal::from(q);
+            character.value(r).checked_mul(hurwitz_euler_maclaurin(value, shift, zeta_terms, terms)?)
+        })
+        .reduce(|| Ok(C0), |u, d| u?.checked_add(d?))
+        .map_err(ctx)?;
+    sum.checked_mul(pow_neg(Decimal::from(q), value, terms).map_err(ctx)?).map_err(ctx)
 }
 
 //##########################################################################################################################
@@ -1,30 +1,43 @@
 
 // Imports
+use std::hash::{ BuildHasher, RandomState };
+
 use rust_decimal::prelude::*;
 use rust_decimal_macros::dec;
 
 // Modules
-use crate::constants::{ PI };
+use crate::constants::{ PI, PI2, PIDIV2, LN_2 };
 
-use crate::error::Error;
+use crate::error::{ Error, ErrorKind };
 
-use crate::complex::types::{ Complex };
-use crate::complex::basic::{ cc_pow };
+use crate::complex::types::{ Complex, Polar };
+use crate::complex::basic::{ c_pow, cc_pow };
+use crate::complex::euler::{ c_exp, c_ln };
 use crate::complex::trigonometry::{ c_cos, c_sin, c_tan, c_atan };
-use crate::complex::riemann::{ zeta };
+use crate::complex::gamma::{ c_gamma, c_lgamma };
+use crate::complex::lambert::{ c_lambert_w };
+use crate::complex::riemann::{ zeta, prime_zeta, riemann_siegel_theta, riemann_siegel_z, zeta_zeros };
+use crate::complex::riemann::{ eta, hurwitz_zeta, polylog, DirichletCharacter, dirichlet_l };
 
 //##########################################################################################################################
 
 // Constants
+const D0: Decimal = Decimal::ZERO;
 const D1: Decimal = Decimal::ONE;
 const D2: Decimal = Decimal::TWO;
 
 const D3: Decimal = dec!(3);
 const D4: Decimal = dec!(4);
+const D5: Decimal = dec!(5);
 const D6: Decimal = dec!(6);
 const D7: Decimal = dec!(7);
+const D10: Decimal = dec!(10);
+const D12: Decimal = dec!(12);
+const D20: Decimal = dec!(20);
 const D24: Decimal = dec!(24);
+const D40: Decimal = dec!(40);
 
+const C0: Complex = Complex::ZERO;
 const CI: Complex = Complex::I;
 const C1: Complex = Complex::ONE;
 
@@ -46,10 +59,88 @@ const ATAN_1IN3_RE: Decimal = dec!(1.4614618538579256382102348165);
 const ATAN_1IN3_IM: Decimal = dec!(-0.3059438579055289264121938212);
 const ATAN_1IN3: Complex = Complex::new(ATAN_1IN3_RE, ATAN_1IN3_IM);
 
+const LGAMMA_2I3_RE: Decimal = dec!(-2.0928517530927333495641886250);
+const LGAMMA_2I3_IM: Decimal = dec!(2.3023965434668676261537076178);
+const LGAMMA_2I3: Complex = Complex::new(LGAMMA_2I3_RE, LGAMMA_2I3_IM);
+
+const LGAMMA_N5DIV2_I1DIV2_RE: Decimal = dec!(-0.9350856212982774786825883849);
+const LGAMMA_N5DIV2_I1DIV2_IM: Decimal = dec!(-8.870962885247459198645824716);
+const LGAMMA_N5DIV2_I1DIV2: Complex = Complex::new(LGAMMA_N5DIV2_I1DIV2_RE, LGAMMA_N5DIV2_I1DIV2_IM);
+
+const LGAMMA_N5DIV2_RE: Decimal = dec!(-0.0562437164976740506725945301);
+const LGAMMA_N5DIV2_IM: Decimal = dec!(-9.424777960769379715387930150);
+const LGAMMA_N5DIV2: Complex = Complex::new(LGAMMA_N5DIV2_RE, LGAMMA_N5DIV2_IM);
+
+const GAMMA_1DIV2_I1_RE: Decimal = dec!(0.3006946172606558162173894638);
+const GAMMA_1DIV2_I1_IM: Decimal = dec!(-0.4249678794331238126098496403);
+const GAMMA_1DIV2_I1: Complex = Complex::new(GAMMA_1DIV2_I1_RE, GAMMA_1DIV2_I1_IM);
+
+const LAMBERT_W0_1I1_RE: Decimal = dec!(0.6569660692304364058739351896);
+const LAMBERT_W0_1I1_IM: Decimal = dec!(0.3254503394134150299892819514);
+const LAMBERT_W0_1I1: Complex = Complex::new(LAMBERT_W0_1I1_RE, LAMBERT_W0_1I1_IM);
+
+const LAMBERT_W1_1I1_RE: Decimal = dec!(-1.3428489407008043011797745967);
+const LAMBERT_W1_1I1_IM: Decimal = dec!(5.2472493742914012056555318166);
+const LAMBERT_W1_1I1: Complex = Complex::new(LAMBERT_W1_1I1_RE, LAMBERT_W1_1I1_IM);
+
+const LAMBERT_WM1_N3DIV10_I1DIV100_RE: Decimal = dec!(-1.7821348043696989504325343191);
+const LAMBERT_WM1_N3DIV10_I1DIV100_IM: Decimal = dec!(-0.0758650921466836802742099882);
+const LAMBERT_WM1_N3DIV10_I1DIV100: Complex = Complex::new(LAMBERT_WM1_N3DIV10_I1DIV100_RE, LAMBERT_WM1_N3DIV10_I1DIV100_IM);
+
+const LAMBERT_W0_N1_RE: Decimal = dec!(-0.3181315052047641353126542516);
+const LAMBERT_W0_N1_IM: Decimal = dec!(1.3372357014306894089011621432);
+const LAMBERT_W0_N1: Complex = Complex::new(LAMBERT_W0_N1_RE, LAMBERT_W0_N1_IM);
+
+const ZETA_2I3_RE: Decimal = dec!(0.7980219851462757206222945007);
+const ZETA_2I3_IM: Decimal = dec!(-0.1137443080529385002159133659);
+const ZETA_2I3: Complex = Complex::new(ZETA_2I3_RE, ZETA_2I3_IM);
+
+const ZETA_N3I4_RE: Decimal = dec!(-0.0337305733882775706758469844);
+const ZETA_N3I4_IM: Decimal = dec!(0.2774499251557093745297676528);
+const ZETA_N3I4: Complex = Complex::new(ZETA_N3I4_RE, ZETA_N3I4_IM);
+
+// First nontrivial zero of zeta on the critical line
+const ZETA_ZERO_1_IM: Decimal = dec!(14.134725141734693790457251984);
+
+// Imaginary parts of the first 5 nontrivial zeros, to 10 decimal places
+const ZETA_ZEROS_5: [Decimal; 5] = [
+    dec!(14.1347251417), dec!(21.0220396388), dec!(25.0108575801), dec!(30.4248761259), dec!(32.9350615877),
+];
+
+const ZETA_20: Decimal = dec!(1.0000009539620338727961131520);
+
+const PRIME_ZETA_2: Decimal = dec!(0.4522474200410654985065433648);
+const PRIME_ZETA_3: Decimal = dec!(0.1747626392994435364231133147);
+
+const PRIME_ZETA_2I1_RE: Decimal = dec!(0.2105460154658571470869661417);
+const PRIME_ZETA_2I1_IM: Decimal = dec!(-0.3233206727871881567227251875);
+const PRIME_ZETA_2I1: Complex = Complex::new(PRIME_ZETA_2I1_RE, PRIME_ZETA_2I1_IM);
+
+const THETA_10: Decimal = dec!(-3.0670743962898952917020135348);
+const SIEGEL_Z_10: Decimal = dec!(-1.5491945461810223890852173019);
+
+const ETA_2I3_RE: Decimal = dec!(1.0420105395745811746616372119);
+const ETA_2I3_IM: Decimal = dec!(0.2070574989958949174656102193);
+const ETA_2I3: Complex = Complex::new(ETA_2I3_RE, ETA_2I3_IM);
+
+const POLYLOG_2_1DIV2: Decimal = dec!(0.5822405264650125059026563202);
+const CATALAN: Decimal = dec!(0.9159655941772190150546035149);
+const L_2_JACOBI_3: Decimal = dec!(0.7813024128964862968671874296);
+
+const L_2_CYCLIC_5_RE: Decimal = dec!(0.9587161227168831553919364293);
+const L_2_CYCLIC_5_IM: Decimal = dec!(0.1455658767850895904617045118);
+const L_2_CYCLIC_5: Complex = Complex::new(L_2_CYCLIC_5_RE, L_2_CYCLIC_5_IM);
+
+//##########################################################################################################################
+
+// Function Signatures
+type Unary = fn(Complex, usize) -> Result<Complex, Error>;
+
 //##########################################################################################################################
 
 // Iteration Terms
 const TEST_ITER: usize = 16;
+const TEST_ZETA: usize = 40;
 
 // Decimal Precision
 const TEST_DIG: u32 = 24;
@@ -82,6 +173,20 @@ fn basic() -> Result<(), Error> {
     // cc_pow(4 + 3i, -1 + 2i) == (4 + 3i) ^ (-1 + 2i)
     let res5 = cc_pow(&mut _c4i3, _cn1i2, TEST_ITER)?.round_dp(TEST_DIG);
     assert_eq!(res5, _cc_pow_test_std);
+    // Equal values hash equally, with or without a cached radius and angle
+    let _state = RandomState::new();
+    let mut _c4i3_cached = D4 + (D3 * CI);
+    _c4i3_cached.arg(TEST_ITER)?;
+    let res6 = _state.hash_one(_c4i3_cached);
+    assert_eq!(res6, _state.hash_one(D4 + (D3 * CI)));
+    // Polar(2, 1 + 2pi) == Polar(2, 1), Polar(0, 1) == Polar(0, 2), and hash equally
+    let _p2 = Polar::new(D2, D1);
+    let _p2_turn = Polar::new(D2, D1 + PI2);
+    assert_eq!(_p2_turn, _p2);
+    let res7 = _state.hash_one(_p2_turn);
+    assert_eq!(res7, _state.hash_one(_p2));
+    let res8 = _state.hash_one(Polar::new(D0, D1));
+    assert_eq!(res8, _state.hash_one(Polar::new(D0, D2)));
     // Return Ok
     Ok(())
 }
@@ -113,14 +218,296 @@ fn trigonometry() -> Result<(), Error> {
 
 //##########################################################################################################################
 
+#[test]
+fn gamma() -> Result<(), Error> {
+    // Set Variables
+    let _c2i3 = D2 + (D3 * CI);
+    let _cn5div2 = -(C1 * D5) / D2;
+    let _cn5div2i1div2 = _cn5div2 + (CI / D2);
+    let _c1div2i1 = (C1 / D2) + CI;
+    // ln(Γ(2 + 3i)) == ln(Γ(2 + 3i))
+    let res1 = c_lgamma(_c2i3, TEST_ITER)?.round_dp(20);
+    assert_eq!(res1, LGAMMA_2I3.round_dp(20));
+    // ln(Γ(-5/2 + i/2)) == ln(Γ(-5/2 + i/2)), by reflection
+    let res2 = c_lgamma(_cn5div2i1div2, TEST_ITER)?.round_dp(20);
+    assert_eq!(res2, LGAMMA_N5DIV2_I1DIV2.round_dp(20));
+    // ln(Γ(conj(z))) == conj(ln(Γ(z)))
+    let res3 = c_lgamma(_cn5div2i1div2.conj(), TEST_ITER)?.round_dp(20);
+    assert_eq!(res3, LGAMMA_N5DIV2_I1DIV2.conj().round_dp(20));
+    // ln(Γ(-5/2)) == ln|Γ(-5/2)| - 3πi
+    let res4 = c_lgamma(_cn5div2, TEST_ITER)?.round_dp(20);
+    assert_eq!(res4, LGAMMA_N5DIV2.round_dp(20));
+    // Γ(1/2 + i) == Γ(1/2 + i)
+    let res5 = c_gamma(_c1div2i1, TEST_ITER)?.round_dp(20);
+    assert_eq!(res5, GAMMA_1DIV2_I1.round_dp(20));
+    // Γ(5) == 24
+    let res6 = c_gamma(C1 * D5, TEST_ITER)?;
+    assert_eq!(res6, C1 * D24);
+    // Γ(-3) == Pole
+    let res7 = c_gamma(-C1 * D3, TEST_ITER);
+    assert_eq!(res7.map_err(|e| e.kind()), Err(ErrorKind::Pole));
+    // Return Ok
+    Ok(())
+}
+
+//##########################################################################################################################
+
+#[test]
+fn lambert() -> Result<(), Error> {
+    // Set Variables
+    let _c1i1 = C1 + CI;
+    let _cn3div10i1div100 = (-D3 / D10) + (CI / dec!(100));
+    // W_0(1 + i) == W_0(1 + i)
+    let res1 = c_lambert_w(_c1i1, 0, TEST_ITER)?.round_dp(20);
+    assert_eq!(res1, LAMBERT_W0_1I1.round_dp(20));
+    // W_1(1 + i) == W_1(1 + i), W_-1(1 - i) == conj(W_1(1 + i))
+    let res2 = c_lambert_w(_c1i1, 1, TEST_ITER)?.round_dp(20);
+    assert_eq!(res2, LAMBERT_W1_1I1.round_dp(20));
+    let res3 = c_lambert_w(_c1i1.conj(), -1, TEST_ITER)?.round_dp(20);
+    assert_eq!(res3, LAMBERT_W1_1I1.conj().round_dp(20));
+    // W_-1(-3/10 + i/100) == W_-1(-3/10 + i/100), near the branch point
+    let res4 = c_lambert_w(_cn3div10i1div100, -1, TEST_ITER)?.round_dp(20);
+    assert_eq!(res4, LAMBERT_WM1_N3DIV10_I1DIV100.round_dp(20));
+    // W_0(-1) == W_0(-1), below the branch point
+    let res5 = c_lambert_w(-C1, 0, TEST_ITER)?.round_dp(20);
+    assert_eq!(res5, LAMBERT_W0_N1.round_dp(20));
+    // W_0(1 + i) * e^(W_0(1 + i)) == 1 + i
+    let res6 = res1.checked_mul(c_exp(res1, TEST_ITER)?)?.round_dp(16);
+    assert_eq!(res6, _c1i1);
+    // W_1(0) == Pole
+    let res7 = c_lambert_w(C0, 1, TEST_ITER).map_err(|e| e.kind());
+    assert_eq!(res7, Err(ErrorKind::Pole));
+    // Return Ok
+    Ok(())
+}
+
+//##########################################################################################################################
+
 #[test]
 fn riemann() -> Result<(), Error> {
     // Set Variables
+    let _zeta_2i3_std = ZETA_2I3.round_dp(TEST_DIG);
+    let _zeta_n3i4_std = ZETA_N3I4.round_dp(TEST_DIG);
     let _c2 = C1 * D2;
-    let _pi_sqr_div6 = (C1 * ((PI * PI) / D6)).round_dp(3);
+    let _c2i3 = D2 + (D3 * CI);
+    let _cn3i4 = -D3 + (D4 * CI);
+    let _zero1 = (C1 / D2) + (ZETA_ZERO_1_IM * CI);
+    let _pi_sqr_div6 = (C1 * ((PI * PI) / D6)).round_dp(TEST_DIG);
+    let _cn1div2 = -C1 / D2;
+    let _cn1div12 = (-C1 / D12).round_dp(TEST_DIG);
     // zeta(2) == (pi ^ 2) / 6
-    let res1 = zeta(_c2, 10_000, TEST_ITER)?.round_dp(3);
+    let res1 = zeta(_c2, TEST_ZETA, TEST_ITER)?.round_dp(TEST_DIG);
     assert_eq!(res1, _pi_sqr_div6);
+    // zeta(2 + 3i) == zeta(2 + 3i)
+    let res2 = zeta(_c2i3, TEST_ZETA, TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res2, _zeta_2i3_std);
+    // zeta(0) == -1/2
+    let res3 = zeta(C0, TEST_ZETA, TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res3, _cn1div2);
+    // zeta(-1) == -1/12
+    let res4 = zeta(-C1, TEST_ZETA, TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res4, _cn1div12);
+    // zeta(-3 + 4i) == zeta(-3 + 4i)
+    let res5 = zeta(_cn3i4, TEST_ZETA, TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res5, _zeta_n3i4_std);
+    // zeta(1/2 + 14.1347...i) == 0
+    let res6 = zeta(_zero1, TEST_ZETA, TEST_ITER)?.round_dp(20);
+    assert_eq!(res6, C0);
+    // zeta(1) == Pole
+    let res7 = zeta(C1, TEST_ZETA, TEST_ITER);
+    assert_eq!(res7.map_err(|e| e.kind()), Err(ErrorKind::Pole));
+    // theta(10) == theta(10)
+    let res8 = riemann_siegel_theta(D10, TEST_ITER)?.round_dp(20);
+    assert_eq!(res8, THETA_10.round_dp(20));
+    // Z(10) == Z(10)
+    let res9 = riemann_siegel_z(D10, TEST_ZETA, TEST_ITER)?.round_dp(20);
+    assert_eq!(res9, SIEGEL_Z_10.round_dp(20));
+    // first 5 zeros == first 5 zeros
+    let res10 = zeta_zeros(5, 10, 2 * TEST_ZETA, TEST_ITER)?;
+    assert_eq!(res10, ZETA_ZEROS_5.to_vec());
+    // P(2) == P(2)
+    let res11 = prime_zeta(_c2, TEST_ZETA, TEST_ITER)?.round_dp(20);
+    assert_eq!(res11, (C1 * PRIME_ZETA_2).round_dp(20));
+    // P(3) == P(3)
+    let res12 = prime_zeta(C1 * D3, TEST_ZETA, TEST_ITER)?.round_dp(20);
+    assert_eq!(res12, (C1 * PRIME_ZETA_3).round_dp(20));
+    // P(2 + i) == P(2 + i)
+    let res13 = prime_zeta(_c2 + CI, TEST_ZETA, TEST_ITER)?.round_dp(20);
+    assert_eq!(res13, PRIME_ZETA_2I1.round_dp(20));
+    // P(1) == InputOutOfRange
+    let res14 = prime_zeta(C1, TEST_ZETA, TEST_ITER);
+    assert_eq!(res14.map_err(|e| e.kind()), Err(ErrorKind::InputOutOfRange));
+    // zeta(20) == zeta(20)
+    let res15 = zeta(C1 * D20, TEST_ZETA, TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res15, (C1 * ZETA_20).round_dp(TEST_DIG));
+    // zeta(1 + 2πi/ln(2)), where 1 - 2^(1-s) == 0
+    let res16 = zeta(Complex::new(D1, dec!(9.064720283654387619255365891)), TEST_ZETA, TEST_ITER)?.round_dp(16);
+    assert_eq!(res16, Complex::new(dec!(1.3465795428363170), dec!(0.1098831367962696)));
+    // zeta(1 + 4πi/ln(2)), where 1 - 2^(1-s) == 0
+    let res17 = zeta(Complex::new(D1, dec!(18.12944056730877523851073178)), TEST_ZETA, TEST_ITER)?.round_dp(16);
+    assert_eq!(res17, Complex::new(dec!(1.8247448778569366), dec!(-0.2160352160667748)));
+    // Return Ok
+    Ok(())
+}
+
+//##########################################################################################################################
+
+#[test]
+fn dirichlet() -> Result<(), Error> {
+    // Set Variables
+    let _c2 = C1 * D2;
+    let _c2i3 = D2 + (D3 * CI);
+    let _c1div2 = C1 / D2;
+    let _c5div2i = Complex::new(dec!(2.5), D1);
+    let _pi_sqr_div2 = (C1 * ((PI * PI) / D2)).round_dp(20);
+    let _chi4 = DirichletCharacter::new(4, vec![C0, C1, C0, -C1])?;
+    // eta(1) == ln(2)
+    let res1 = eta(C1, TEST_ZETA, TEST_ITER)?.round_dp(20);
+    assert_eq!(res1, (C1 * LN_2).round_dp(20));
+    // eta(2 + 3i) == eta(2 + 3i)
+    let res2 = eta(_c2i3, TEST_ZETA, TEST_ITER)?.round_dp(20);
+    assert_eq!(res2, ETA_2I3.round_dp(20));
+    // zeta(2, 1/2) == (pi ^ 2) / 2
+    let res3 = hurwitz_zeta(_c2, D1 / D2, TEST_ZETA, TEST_ITER)?.round_dp(20);
+    assert_eq!(res3, _pi_sqr_div2);
+    // zeta(2 + 3i, 1) == zeta(2 + 3i)
+    let res4 = hurwitz_zeta(_c2i3, D1, TEST_ZETA, TEST_ITER)?.round_dp(20);
+    assert_eq!(res4, ZETA_2I3.round_dp(20));
+    // Li_2(1/2) == Li_2(1/2)
+    let res5 = polylog(_c2, _c1div2, TEST_ZETA, TEST_ITER)?.round_dp(20);
+    assert_eq!(res5, (C1 * POLYLOG_2_1DIV2).round_dp(20));
+    // L(2, chi_4) == Catalan's constant
+    let res6 = dirichlet_l(_c2, &_chi4, TEST_ZETA, TEST_ITER)?.round_dp(20);
+    assert_eq!(res6, (C1 * CATALAN).round_dp(20));
+    // L(2, (./3)) == L(2, (./3))
+    let res7 = dirichlet_l(_c2, &DirichletCharacter::jacobi(3)?, TEST_ZETA, TEST_ITER)?.round_dp(20);
+    assert_eq!(res7, (C1 * L_2_JACOBI_3).round_dp(20));
+    // L(2, chi_5) == L(2, chi_5)
+    let res8 = dirichlet_l(_c2, &DirichletCharacter::cyclic(5, 1, TEST_ITER)?, TEST_ZETA, TEST_ITER)?.round_dp(20);
+    assert_eq!(res8, L_2_CYCLIC_5.round_dp(20));
+    // zeta(1, 1/2) == Pole
+    let res9 = hurwitz_zeta(C1, D1 / D2, TEST_ZETA, TEST_ITER);
+    assert_eq!(res9.map_err(|e| e.kind()), Err(ErrorKind::Pole));
+    // zeta(2, 0) == InputOutOfRange
+    let res10 = hurwitz_zeta(_c2, D0, TEST_ZETA, TEST_ITER);
+    assert_eq!(res10.map_err(|e| e.kind()), Err(ErrorKind::InputOutOfRange));
+    // Li_2(2) == InputOutOfRange
+    let res11 = polylog(_c2, _c2, TEST_ZETA, TEST_ITER);
+    assert_eq!(res11.map_err(|e| e.kind()), Err(ErrorKind::InputOutOfRange));
+    // chi(2) != 0 mod 4 == DomainError
+    let res12 = DirichletCharacter::new(4, vec![C0, C1, C1, -C1]);
+    assert_eq!(res12.map_err(|e| e.kind()).err(), Some(ErrorKind::DomainError));
+    // L(1, chi_4) == pi / 4
+    let res13 = dirichlet_l(C1, &_chi4, TEST_ZETA, TEST_ITER)?.round_dp(20);
+    assert_eq!(res13, (C1 * (PI / D4)).round_dp(20));
+    // L(1, (./3)) == pi / (3 * sqrt(3))
+    let res14 = dirichlet_l(C1, &DirichletCharacter::jacobi(3)?, TEST_ZETA, TEST_ITER)?.round_dp(20);
+    assert_eq!(res14, (C1 * dec!(0.6045997880780726168646927525)).round_dp(20));
+    // L(1, chi_5) == L(1, chi_5)
+    let res15 = dirichlet_l(C1, &DirichletCharacter::cyclic(5, 1, TEST_ITER)?, TEST_ZETA, TEST_ITER)?.round_dp(16);
+    assert_eq!(res15, Complex::new(dec!(0.8648062659772100), dec!(0.2041530661383851)));
+    // L(1, principal mod 4) == Pole
+    let res16 = dirichlet_l(C1, &DirichletCharacter::principal(4)?, TEST_ZETA, TEST_ITER);
+    assert_eq!(res16.map_err(|e| e.kind()), Err(ErrorKind::Pole));
+    // (./0) == InputOutOfRange
+    let res17 = DirichletCharacter::jacobi(0);
+    assert_eq!(res17.map_err(|e| e.kind()).err(), Some(ErrorKind::InputOutOfRange));
+    // Li_2(9/10) == Li_2(9/10)
+    let res18 = polylog(_c2, C1 * dec!(0.9), TEST_ZETA, TEST_ITER)?.round_dp(20);
+    assert_eq!(res18, (C1 * dec!(1.2997147230049587251710604942)).round_dp(20));
+    // Li_2(99/100) == Li_2(99/100)
+    let res19 = polylog(_c2, C1 * dec!(0.99), TEST_ZETA, TEST_ITER)?.round_dp(20);
+    assert_eq!(res19, (C1 * dec!(1.5886254480763753270312294740)).round_dp(20));
+    // Li_(5/2 + i)(-3/5 + 7i/10) == Li_(5/2 + i)(-3/5 + 7i/10)
+    let res20 = polylog(_c5div2i, Complex::new(dec!(-0.6), dec!(0.7)), TEST_ZETA, TEST_ITER)?.round_dp(20);
+    assert_eq!(res20, Complex::new(dec!(-0.6732510543316149995487976017), dec!(0.5911477217766208105518008631)).round_dp(20));
+    // Return Ok
+    Ok(())
+}
+
+//##########################################################################################################################
+
+#[test]
+fn edge_inputs() -> Result<(), Error> {
+    // Set Variables
+    let tiny = Decimal::new(1, 28);
+    let values = [
+        C0, C1, CI, -CI, Complex::new(tiny, -tiny), Complex::new(D40, D40), Complex::new(-D40, D40),
+        Complex::new(Decimal::MAX, D0), Complex::new(D0, Decimal::MIN), Complex::new(Decimal::MAX, Decimal::MAX),
+    ];
+    let unary: [(&str, Unary); 5] = [
+        ("c_exp", c_exp), ("c_cos", c_cos), ("c_sin", c_sin), ("c_tan", c_tan), ("c_atan", c_atan),
+    ];
+    // Every public function returns instead of panicking
+    for value in values {
+        for (name, func) in unary {
+            if let Err(err) = func(value, TEST_ITER) { assert_eq!(err.func(), Some(name)) };
+        };
+        let _ = c_ln(&mut value.clone(), TEST_ITER);
+        let _ = c_pow(value, 3);
+        let _ = c_gamma(value, TEST_ITER);
+        let _ = c_lgamma(value, TEST_ITER);
+        let _ = c_lambert_w(value, 0, TEST_ITER);
+        let _ = c_lambert_w(value, -1, TEST_ITER);
+        let _ = zeta(value, 4, TEST_ITER);
+        let _ = prime_zeta(value, 4, TEST_ITER);
+        let _ = eta(value, 4, TEST_ITER);
+        let _ = hurwitz_zeta(value, D1 / D3, 4, TEST_ITER);
+        let _ = polylog(D2 * C1, value, 4, TEST_ITER);
+        let _ = value.clone().to_polar(TEST_ITER);
+        let _ = value.checked_inv();
+        let _ = value.checked_scale(Decimal::MAX);
+        let _ = value.checked_unscale(D0);
+        let _ = value.checked_radius_sqr();
+        let _ = value.checked_add_re(Decimal::MAX);
+        let _ = value.checked_sub_re(Decimal::MAX);
+        let _ = value.inv();
+        let mut polar = Polar::new(value.re(), value.im());
+        let _ = polar.to_cartesian(TEST_ITER);
+        let _ = polar.checked_inv();
+        let _ = polar.checked_scale(Decimal::MAX);
+        let _ = polar.checked_unscale(D0);
+        let _ = polar.checked_inv().and_then(|p| p.checked_scale(Decimal::MAX));
+        for other in values {
+            let _ = cc_pow(&mut value.clone(), other, TEST_ITER);
+            let _ = value.checked_add(other);
+            let _ = value.checked_sub(other);
+            let _ = value.checked_mul(other);
+            let _ = value.checked_div(other);
+            let _ = polar.checked_mul(Polar::new(other.re(), other.im()));
+            let _ = polar.checked_div(Polar::new(other.re(), other.im()));
+        };
+    };
+    // (1 + i) / 0 == DivisionByZero
+    let res1 = (C1 + CI).checked_div(C0).map_err(|e| e.kind());
+    assert_eq!(res1, Err(ErrorKind::DivisionByZero));
+    // (1 + i) / (1 - i) == i
+    let res2 = (C1 + CI).checked_div(C1 - CI)?;
+    assert_eq!(res2, CI);
+    // 1 / 2i == -i/2
+    let res3 = Complex::new(D0, D2).checked_inv()?;
+    assert_eq!(res3, -CI / D2);
+    // (MAX + MAX i) == 1 in polar form is false rather than a panic
+    let res4 = Complex::new(Decimal::MAX, Decimal::MAX) == Polar::ONE;
+    assert!(!res4);
+    let res5 = Polar::new(Decimal::MAX, D1) == C1;
+    assert!(!res5);
+    // 1 / 0 == DivisionByZero
+    let res6 = C0.inv().map_err(|e| e.kind());
+    assert_eq!(res6, Err(ErrorKind::DivisionByZero));
+    // (MAX + i) + 1 == AddOverflow, (1 + i) - 1 == i
+    let res7 = Complex::new(Decimal::MAX, D1).checked_add_re(D1).map_err(|e| e.kind());
+    assert_eq!(res7, Err(ErrorKind::AddOverflow));
+    let res8 = (C1 + CI).checked_sub_re(D1)?;
+    assert_eq!(res8, CI);
+    // 2i / 0 == DivisionByZero, 2i * 3 == 6i
+    let res9 = Polar::new(D2, PIDIV2).checked_unscale(D0).map_err(|e| e.kind());
+    assert_eq!(res9, Err(ErrorKind::DivisionByZero));
+    let res10 = Polar::new(D2, PIDIV2).checked_scale(D3)?;
+    assert_eq!(res10, Polar::new(dec!(6), PIDIV2));
+    // MAX * MAX == MultiplyOverflow
+    let res11 = Polar::new(Decimal::MAX, D0).checked_scale(Decimal::MAX).map_err(|e| e.kind());
+    assert_eq!(res11, Err(ErrorKind::MultiplyOverflow));
     // Return Ok
     Ok(())
 }
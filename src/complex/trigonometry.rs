@@ -3,7 +3,7 @@
 use rust_decimal::prelude::*;
 
 // Modules
-use crate::error::Error;
+use crate::error::{ Error, ErrorKind };
 
 use crate::complex::types::{ Complex };
 use crate::complex::euler::{ c_exp, c_ln };
@@ -11,23 +11,35 @@ use crate::complex::euler::{ c_exp, c_ln };
 //##########################################################################################################################
 
 const D0: Decimal = Decimal::ZERO;
-const D1: Decimal = Decimal::ONE;
 const D2: Decimal = Decimal::TWO;
 
+const C1: Complex = Complex::ONE;
 const CI: Complex = Complex::I;
 const CI2: Complex = Complex::new(D0, D2);
 
 //##########################################################################################################################
 
+/// (e^zi, e^-zi)
+#[inline]
+fn exp_i_pair(
+    value: Complex,
+    terms: usize
+) -> Result<(Complex, Complex), Error> {
+    let ei = c_exp(value.checked_mul(CI)?, terms)?;
+    Ok((ei, ei.checked_inv()?))
+}
+
+//##########################################################################################################################
+
 /// cos(z) = (e^zi + e^-zi) / 2
 #[inline]
 pub fn c_cos(
     value: Complex,
     terms: usize
 ) -> Result<Complex, Error> {
-    let ei = c_exp(value * CI, terms)?;
-    let ein = D1 / ei;
-    Ok((ei + ein) / D2)
+    let ctx = |e: Error| e.context("c_cos", value);
+    let (ei, ein) = exp_i_pair(value, terms).map_err(ctx)?;
+    ei.checked_add(ein).and_then(|v| v.checked_unscale(D2)).map_err(ctx)
 }
 
 //##########################################################################################################################
@@ -38,9 +50,9 @@ pub fn c_sin(
     value: Complex,
     terms: usize
 ) -> Result<Complex, Error> {
-    let ei = c_exp(value * CI, terms)?;
-    let ein = D1 / ei;
-    Ok((ei - ein) / CI2)
+    let ctx = |e: Error| e.context("c_sin", value);
+    let (ei, ein) = exp_i_pair(value, terms).map_err(ctx)?;
+    ei.checked_sub(ein).and_then(|v| v.checked_div(CI2)).map_err(ctx)
 }
 
 //##########################################################################################################################
@@ -51,12 +63,12 @@ pub fn c_tan(
     value: Complex,
     terms: usize
 ) -> Result<Complex, Error> {
-    let ei = c_exp(value * CI, terms)?;
-    let ein = D1 / ei;
-    Ok(
-        ((ei - ein) / CI2) /
-        ((ei + ein) / D2)
-    )
+    let ctx = |e: Error| e.context("c_tan", value);
+    let (ei, ein) = exp_i_pair(value, terms).map_err(ctx)?;
+    let sin = ei.checked_sub(ein).and_then(|v| v.checked_div(CI2)).map_err(ctx)?;
+    let cos = ei.checked_add(ein).and_then(|v| v.checked_unscale(D2)).map_err(ctx)?;
+    if cos.is_zero() { Err(ctx(ErrorKind::Pole.into()))? };
+    sin.checked_div(cos).map_err(ctx)
 }
 
 //##########################################################################################################################
@@ -67,10 +79,13 @@ pub fn c_atan(
     value: Complex,
     terms: usize
 ) -> Result<Complex, Error> {
-    let term1 = D1 + (CI * value);
-    let term2 = D1 - (CI * value);
-    let ln_term = c_ln(&mut (term1 / term2), terms)?;
-    Ok(ln_term / CI2)
+    let ctx = |e: Error| e.context("c_atan", value);
+    let zi = CI.checked_mul(value).map_err(ctx)?;
+    let term1 = C1.checked_add(zi).map_err(ctx)?;
+    let term2 = C1.checked_sub(zi).map_err(ctx)?;
+    if term2.is_zero() { Err(ctx(ErrorKind::Pole.into()))? };
+    let ln_term = c_ln(&mut term1.checked_div(term2).map_err(ctx)?, terms).map_err(ctx)?;
+    ln_term.checked_div(CI2).map_err(ctx)
 }
 
 //##########################################################################################################################
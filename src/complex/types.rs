@@ -1,6 +1,7 @@
 
 // Imports
 use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::hash::{Hash, Hasher};
 use std::fmt;
 
 use rust_decimal::prelude::*;
@@ -8,7 +9,7 @@ use rust_decimal::prelude::*;
 // Modules
 use crate::constants::{ PI, PI2, PIDIV2 };
 
-use crate::error::Error;
+use crate::error::{ Error, ErrorKind };
 use crate::sqrt::{ d_sqrt };
 use crate::trigonometry::{ d_cos, d_sin, d_atan2 };
 
@@ -29,7 +30,7 @@ const STD_ITER: usize = 32;
 //##########################################################################################################################
 
 /// A complex number in Cartesian form. `z = a + i * b`
-#[derive(Copy, Clone, Hash, Debug)]
+#[derive(Copy, Clone, Debug)]
 pub struct Complex {
     /// Real portion of the complex number
     _re: Decimal,
@@ -87,7 +88,7 @@ impl Complex {
             else if self._im == D0 { self._re.abs() }
             else if self._re == D0 { self._im.abs() }
             else {
-                let _sqr = self.radius_sqr();
+                let _sqr = self.checked_radius_sqr()?;
                 d_sqrt(_sqr, terms)?
             }
         )
@@ -96,8 +97,12 @@ impl Complex {
     /// Get Radius of Complex number.
     #[inline]
     pub fn radius(&mut self, terms: usize) -> Result<Decimal, Error> {
-        if self._radius == None { self._radius = Some(self.calc_radius(terms)?) };
-        Ok(self._radius.ok_or(Error::OptionInvalid)?.clone())
+        if self._radius.is_none() {
+            let input = *self;
+            let res = self.calc_radius(terms).map_err(|e| e.context("Complex::radius", input))?;
+            self._radius = Some(res);
+        };
+        Ok(self._radius.ok_or(ErrorKind::OptionInvalid)?)
     }
 }
 
@@ -111,8 +116,8 @@ impl Complex {
             if self.is_zero() { self.radius(terms)? }
             else {
                 let radius  = self.radius(terms)?;
-                let cos_arg = self._re / radius;
-                let sin_arg = self._im / radius;
+                let cos_arg = self._re.checked_div(radius).ok_or(ErrorKind::DivisionByZero)?;
+                let sin_arg = self._im.checked_div(radius).ok_or(ErrorKind::DivisionByZero)?;
                 d_atan2(cos_arg, sin_arg, terms)?
             }
         )
@@ -121,8 +126,12 @@ impl Complex {
     /// Get Angle of complex number.
     #[inline]
     pub fn arg(&mut self, terms: usize) -> Result<Decimal, Error> {
-        if self._arg == None { self._arg = Some(self.calc_arg(terms)?) };
-        Ok(self._arg.ok_or(Error::OptionInvalid)?.clone())
+        if self._arg.is_none() {
+            let input = *self;
+            let res = self.calc_arg(terms).map_err(|e| e.context("Complex::arg", input))?;
+            self._arg = Some(res);
+        };
+        Ok(self._arg.ok_or(ErrorKind::OptionInvalid)?)
     }
 }
 
@@ -167,10 +176,10 @@ impl Complex {
         Self::new(re, im)
     }
 
-    /// Returns `1/self`
+    /// Returns `1/self`, or `DivisionByZero` when `self` is zero.
     #[inline]
-    pub fn inv(&self) -> Self {
-        D1 / (*self)
+    pub fn inv(&self) -> Result<Self, Error> {
+        self.checked_inv()
     }
 
     /// Round complex terms to integer.
@@ -226,6 +235,100 @@ impl Complex {
 
 //##########################################################################################################################
 
+impl Complex {
+    /// Checked squared radius. `re^2 + im^2`
+    #[inline]
+    pub fn checked_radius_sqr(&self) -> Result<Decimal, Error> {
+        let re_sqr = self._re.checked_mul(self._re).ok_or(ErrorKind::MultiplyOverflow)?;
+        let im_sqr = self._im.checked_mul(self._im).ok_or(ErrorKind::MultiplyOverflow)?;
+        Ok(re_sqr.checked_add(im_sqr).ok_or(ErrorKind::AddOverflow)?)
+    }
+
+    /// Checked addition. (a + c) + i (b + d)
+    #[inline]
+    pub fn checked_add(&self, other: Self) -> Result<Self, Error> {
+        let re = self._re.checked_add(other.re()).ok_or(ErrorKind::AddOverflow)?;
+        let im = self._im.checked_add(other.im()).ok_or(ErrorKind::AddOverflow)?;
+        Ok(Self::new(re, im))
+    }
+
+    /// Checked subtraction. (a - c) + i (b - d)
+    #[inline]
+    pub fn checked_sub(&self, other: Self) -> Result<Self, Error> {
+        let re = self._re.checked_sub(other.re()).ok_or(ErrorKind::AddOverflow)?;
+        let im = self._im.checked_sub(other.im()).ok_or(ErrorKind::AddOverflow)?;
+        Ok(Self::new(re, im))
+    }
+
+    /// Checked multiplication. (a*c - b*d) + i (a*d + b*c)
+    #[inline]
+    pub fn checked_mul(&self, other: Self) -> Result<Self, Error> {
+        let mul = |u: Decimal, d: Decimal| u.checked_mul(d).ok_or(ErrorKind::MultiplyOverflow);
+        let re = mul(self._re, other.re())?
+            .checked_sub(mul(self._im, other.im())?)
+            .ok_or(ErrorKind::AddOverflow)?;
+        let im = mul(self._re, other.im())?
+            .checked_add(mul(self._im, other.re())?)
+            .ok_or(ErrorKind::AddOverflow)?;
+        Ok(Self::new(re, im))
+    }
+
+    /// Checked division. [(a*c + b*d) / (c*c + d*d)] + i [(b*c - a*d) / (c*c + d*d)]
+    #[inline]
+    pub fn checked_div(&self, other: Self) -> Result<Self, Error> {
+        if other.is_zero() { Err(ErrorKind::DivisionByZero)? };
+        // Divide by the larger component first to keep terms small
+        let scale =
+            if other._re.abs() >= other._im.abs() { other._re.abs() }
+            else                                  { other._im.abs() };
+        let unit = other.checked_unscale(scale)?;
+        let num = self.checked_mul(unit.conj())?;
+        let den = unit.checked_radius_sqr()?
+            .checked_mul(scale)
+            .ok_or(ErrorKind::MultiplyOverflow)?;
+        num.checked_unscale(den)
+    }
+
+    /// Checked inverse. `1/self`
+    #[inline]
+    pub fn checked_inv(&self) -> Result<Self, Error> {
+        Self::ONE.checked_div(*self)
+    }
+
+    /// Checked sum with the scalar `value`. (a + t) + i b
+    #[inline]
+    pub fn checked_add_re(&self, value: Decimal) -> Result<Self, Error> {
+        let re = self._re.checked_add(value).ok_or(ErrorKind::AddOverflow)?;
+        Ok(Self::new(re, self._im))
+    }
+
+    /// Checked difference with the scalar `value`. (a - t) + i b
+    #[inline]
+    pub fn checked_sub_re(&self, value: Decimal) -> Result<Self, Error> {
+        let re = self._re.checked_sub(value).ok_or(ErrorKind::AddOverflow)?;
+        Ok(Self::new(re, self._im))
+    }
+
+    /// Checked product by the scalar `value`.
+    #[inline]
+    pub fn checked_scale(&self, value: Decimal) -> Result<Self, Error> {
+        let re = self._re.checked_mul(value).ok_or(ErrorKind::MultiplyOverflow)?;
+        let im = self._im.checked_mul(value).ok_or(ErrorKind::MultiplyOverflow)?;
+        Ok(Self::new(re, im))
+    }
+
+    /// Checked quotient by the scalar `value`.
+    #[inline]
+    pub fn checked_unscale(&self, value: Decimal) -> Result<Self, Error> {
+        if value == D0 { Err(ErrorKind::DivisionByZero)? };
+        let re = self._re.checked_div(value).ok_or(ErrorKind::MultiplyOverflow)?;
+        let im = self._im.checked_div(value).ok_or(ErrorKind::MultiplyOverflow)?;
+        Ok(Self::new(re, im))
+    }
+}
+
+//##########################################################################################################################
+
 impl Complex {
     /// A constant representing the Imaginary unit - sqrt(-1).
     pub const I: Self = Complex::new(D0, D1);
@@ -282,6 +385,14 @@ impl PartialEq for Complex {
     }
 }
 
+/// Hashes the parts PartialEq compares, leaving out the cached radius and angle
+impl Hash for Complex {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self._re.hash(state);
+        self._im.hash(state);
+    }
+}
+
 impl PartialEq<Decimal> for Complex {
     fn eq(&self, other: &Decimal) -> bool {
         ( &self._re == other ) &&
@@ -298,7 +409,8 @@ impl PartialEq<Complex> for Decimal {
 
 impl PartialEq<Polar> for Complex {
     fn eq(&self, other: &Polar) -> bool {
-        other == &self.clone().to_polar(STD_ITER).unwrap()
+        // Values beyond the conversion compare unequal
+        self.clone().to_polar(STD_ITER).is_ok_and(|polar| other == &polar)
     }
 }
 
@@ -315,12 +427,12 @@ impl Neg for Complex {
     }
 }
 
-impl<'a> Neg for &'a Complex {
+impl Neg for &Complex {
     type Output = Complex;
 
     #[inline]
     fn neg(self) -> Complex {
-        -self.clone()
+        -*self
     }
 }
 
@@ -457,7 +569,7 @@ impl Div<Complex> for Decimal {
 //##########################################################################################################################
 
 /// A complex number in Polar form. `z = |z| * (cos(arg) + i sin(arg))`
-#[derive(Copy, Clone, Hash, Debug)]
+#[derive(Copy, Clone, Debug)]
 pub struct Polar {
     /// Radius of complex number |self|
     _radius: Decimal,
@@ -513,15 +625,19 @@ impl Polar {
         Ok(
             d_cos(self._arg, terms)?
                 .checked_mul(self._radius)
-                .ok_or(Error::MultiplyOverflow)?
+                .ok_or(ErrorKind::MultiplyOverflow)?
         )
     }
 
     /// Get Real part of Complex number.
     #[inline]
     pub fn re(&mut self, terms: usize) -> Result<Decimal, Error> {
-        if self._re == None { self._re = Some(self.calc_re(terms)?) };
-        Ok(self._re.ok_or(Error::OptionInvalid)?.clone())
+        if self._re.is_none() {
+            let input = *self;
+            let res = self.calc_re(terms).map_err(|e| e.context("Polar::re", input))?;
+            self._re = Some(res);
+        };
+        Ok(self._re.ok_or(ErrorKind::OptionInvalid)?)
     }
 }
 
@@ -534,15 +650,19 @@ impl Polar {
         Ok(
             d_sin(self._arg, terms)?
                 .checked_mul(self._radius)
-                .ok_or(Error::MultiplyOverflow)?
+                .ok_or(ErrorKind::MultiplyOverflow)?
         )
     }
 
     /// Get Imaginary part of Complex number.
     #[inline]
     pub fn im(&mut self, terms: usize) -> Result<Decimal, Error> {
-        if self._im == None { self._im = Some(self.calc_im(terms)?) };
-        Ok(self._im.ok_or(Error::OptionInvalid)?.clone())
+        if self._im.is_none() {
+            let input = *self;
+            let res = self.calc_im(terms).map_err(|e| e.context("Polar::im", input))?;
+            self._im = Some(res);
+        };
+        Ok(self._im.ok_or(ErrorKind::OptionInvalid)?)
     }
 }
 
@@ -571,17 +691,53 @@ impl Polar {
     #[inline]
     pub fn to_std(&mut self) -> Self {
         // Fix Angle
-        let mut arg = self._arg + if self._radius < D0 {PI} else {D0};
-        if (arg < -PI) || (PI < arg) {
-            arg = arg - ((arg / PI2).floor() * PI2);
-        };
-             if arg >  PI { arg = arg - PI2; }
-        else if arg < -PI { arg = arg + PI2; };
+        let mut arg = (self._arg % PI2) + if self._radius < D0 {PI} else {D0};
+             if arg >  PI { arg -= PI2; }
+        else if arg < -PI { arg += PI2; };
         // Assign new values
         self._radius = self._radius.abs();
         self._arg    = arg;
         // Return cloned self
-        self.clone()
+        *self
+    }
+}
+
+//##########################################################################################################################
+
+impl Polar {
+    /// Checked multiplication. r1*r2 (cos(θ1 + θ2) + i sin(θ1 + θ2))
+    #[inline]
+    pub fn checked_mul(&self, other: Self) -> Result<Self, Error> {
+        let radius = self._radius.checked_mul(other.radius()).ok_or(ErrorKind::MultiplyOverflow)?;
+        let arg    = self._arg.checked_add(other.arg()).ok_or(ErrorKind::AddOverflow)?;
+        Ok(Self::new(radius, arg).to_std())
+    }
+
+    /// Checked division. r1/r2 (cos(θ1 - θ2) + i sin(θ1 - θ2))
+    #[inline]
+    pub fn checked_div(&self, other: Self) -> Result<Self, Error> {
+        if other.radius() == D0 { Err(ErrorKind::DivisionByZero)? };
+        let radius = self._radius.checked_div(other.radius()).ok_or(ErrorKind::MultiplyOverflow)?;
+        let arg    = self._arg.checked_sub(other.arg()).ok_or(ErrorKind::AddOverflow)?;
+        Ok(Self::new(radius, arg).to_std())
+    }
+
+    /// Checked inverse. `1/self`
+    #[inline]
+    pub fn checked_inv(&self) -> Result<Self, Error> {
+        Self::ONE.checked_div(*self)
+    }
+
+    /// Checked product by the scalar `value`.
+    #[inline]
+    pub fn checked_scale(&self, value: Decimal) -> Result<Self, Error> {
+        self.checked_mul(Self::new(value, D0))
+    }
+
+    /// Checked quotient by the scalar `value`.
+    #[inline]
+    pub fn checked_unscale(&self, value: Decimal) -> Result<Self, Error> {
+        self.checked_div(Self::new(value, D0))
     }
 }
 
@@ -630,6 +786,18 @@ impl PartialEq for Polar {
     }
 }
 
+/// Hashes the standard form PartialEq compares, leaving out the cached real and imaginary parts
+impl Hash for Polar {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        if self._radius == D0 { D0.hash(state) }
+        else {
+            let _self = self.clone().to_std();
+            _self.radius().hash(state);
+            _self.arg().hash(state);
+        }
+    }
+}
+
 impl PartialEq<Decimal> for Polar {
     fn eq(&self, other: &Decimal) -> bool {
         let arg = if other >= &D0 {D0} else {PI};
@@ -646,7 +814,8 @@ impl PartialEq<Polar> for Decimal {
 
 impl PartialEq<Complex> for Polar {
     fn eq(&self, other: &Complex) -> bool {
-        other == &self.clone().to_cartesian(STD_ITER).unwrap()
+        // Values beyond the conversion compare unequal
+        self.clone().to_cartesian(STD_ITER).is_ok_and(|complex| other == &complex)
     }
 }
 
@@ -663,12 +832,12 @@ impl Neg for Polar {
     }
 }
 
-impl<'a> Neg for &'a Polar {
+impl Neg for &Polar {
     type Output = Polar;
 
     #[inline]
     fn neg(self) -> Polar {
-        -self.clone()
+        -*self
     }
 }
 
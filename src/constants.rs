@@ -4,8 +4,8 @@ use rust_decimal::prelude::*;
 use rust_decimal_macros::dec;
 
 // Modules
-use crate::error::Error;
-use crate::basic::{ dec, da_pow };
+use crate::error::{ Error, ErrorKind };
+use crate::basic::{ Terms, dec, da_pow, d_series };
 
 use crate::multiplex::types::{ Multiplex };
 use crate::multiplex::basic::{ ma_pow };
@@ -17,6 +17,8 @@ pub const PI2: Decimal = dec!(6.2831853071795864769252867666);
 pub const PIDIV2: Decimal = dec!(1.5707963267948966192313216916);
 pub const PI3DIV2: Decimal = dec!(4.7123889803846898576939650749);
 pub const PIDIV4: Decimal = dec!(0.7853981633974483096156608458);
+pub const PIDIV3: Decimal = dec!(1.0471975511965977461542144611);
+pub const PI2DIV3: Decimal = dec!(2.0943951023931954923084289222);
 
 //##########################################################################################################################
 
@@ -33,6 +35,12 @@ pub const TAN_PIDIV36: Decimal = dec!(0.0874886635259240052220186694);
 pub const E: Decimal = dec!(2.7182818284590452353602874714); // (e)
 pub const D1DIVE: Decimal = dec!(0.3678794411714423215955237702); // (1 / e)
 pub const E_SQR: Decimal = dec!(7.3890560989306502272304274606); // (e ^ 2)
+pub const LN_2: Decimal = dec!(0.6931471805599453094172321215); // ln(2)
+pub const EULER_GAMMA: Decimal = dec!(0.5772156649015328606065120901); // (γ)
+pub const LN_PI: Decimal = dec!(1.1447298858494001741434273514); // ln(pi)
+pub const LN_SQRT_PI2: Decimal = dec!(0.9189385332046727417803297364); // ln(2 * pi) / 2
+pub const SQRT_PI: Decimal = dec!(1.7724538509055160272981674833); // sqrt(pi)
+pub const D2DIV_SQRT_PI: Decimal = dec!(1.1283791670955125738961589031); // 2 / sqrt(pi)
 pub const E_POW1DIV5: Decimal = dec!(1.2214027581601698339210719946); // (e ^ (1 / 4))
 
 //##########################################################################################################################
@@ -65,7 +73,6 @@ pub const SQRT_LOWER_VAL_P: Decimal = dec!(0.975); // sqrt(0.975 ^ 2)
 
 //##########################################################################################################################
 
-const D0: Decimal = Decimal::ZERO;
 const D1: Decimal = Decimal::ONE;
 const D2: Decimal = Decimal::TWO;
 
@@ -79,31 +86,45 @@ const D239: Decimal = dec!(239);
 #[inline]
 fn d_pi_term(
     value: Decimal,
-    terms: usize
-) -> Result<Decimal, Error> {
+    terms: Terms
+) -> Result<(Decimal, usize), Error> {
     let mut acc1: (Decimal, usize) = (D1, 0);
     let mut acc2: (Multiplex, usize) = (Multiplex::new(), 0);
     // Iterate over Series
-    (1..=terms).into_iter()
-        .map(|n| Ok(
-            da_pow(-D1, n + 1, &mut acc1)? * (
-                ma_pow(value, (2 * n) - 1, &mut acc2)? / ((D2 * dec(n)) - D1)
-            ).squash()?
-        ))
-        .reduce(|u, d| Ok(
-            u?.checked_add(d?).ok_or(Error::AddOverflow)?
-        ))
-        .unwrap_or(Ok(D0))
+    d_series(1, terms, |n| Ok(
+        da_pow(-D1, n + 1, &mut acc1)? * (
+            ma_pow(value, (2 * n) - 1, &mut acc2)? / ((D2 * dec(n)) - D1)
+        ).squash()?
+    ))
 }
 
 /// pi = 4 * ((4 * pi_term(1 / 5)) - pi_term(1 / 239))
+#[inline]
+fn d_pi_helper(
+    terms: Terms
+) -> Result<(Decimal, usize), Error> {
+    let (term1, used1) = d_pi_term(D1 / D5, terms)?;
+    let (term2, used2) = d_pi_term(D1 / D239, terms)?;
+    Ok((D4 * ((D4 * term1) - term2), used1 + used2))
+}
+
 #[inline]
 pub fn d_pi(
     terms: usize
 ) -> Result<Decimal, Error> {
-    let term1 = d_pi_term(D1 / D5, terms)?;
-    let term2 = d_pi_term(D1 / D239, terms)?;
-    Ok(D4 * ((D4 * term1) - term2))
+    d_pi_helper(Terms::Fixed(terms))
+        .map(|(res, _)| res)
+        .map_err(|e| e.context("d_pi", terms))
+}
+
+/// pi to `digits` decimal places, with the number of series terms used.
+#[inline]
+pub fn d_pi_dp(
+    digits: u32
+) -> Result<(Decimal, usize), Error> {
+    if digits > 28 { Err(Error::from(ErrorKind::InputOutOfRange).context("d_pi_dp", digits))? };
+    d_pi_helper(Terms::Digits(digits))
+        .map_err(|e| e.context("d_pi_dp", digits))
 }
 
 //##########################################################################################################################
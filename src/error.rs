@@ -1,9 +1,110 @@
+// Imports
+use std::fmt;
 
-#[derive(PartialEq, Debug)]
-pub enum Error {
+//##########################################################################################################################
+
+/// Kind of failure raised by the crate.
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug)]
+pub enum ErrorKind {
     AddOverflow,
     MultiplyOverflow,
     InputOutOfRange,
     IteratorError,
     OptionInvalid,
+    Pole,
+    DivisionByZero,
+    Underflow,
+    NotConverged,
+    DomainError,
+}
+
+impl fmt::Display for ErrorKind {
+    /// Format string
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let message = match self {
+            ErrorKind::AddOverflow      => "addition overflow",
+            ErrorKind::MultiplyOverflow => "multiplication overflow",
+            ErrorKind::InputOutOfRange  => "input out of range",
+            ErrorKind::IteratorError    => "iterator error",
+            ErrorKind::OptionInvalid    => "invalid option",
+            ErrorKind::Pole             => "pole of the function",
+            ErrorKind::DivisionByZero   => "division by zero",
+            ErrorKind::Underflow        => "underflow",
+            ErrorKind::NotConverged     => "series did not converge",
+            ErrorKind::DomainError      => "input outside of the function domain",
+        };
+        write!(f, "{}", message)
+    }
+}
+
+//##########################################################################################################################
+
+/// Error carrying its kind, the function that failed and the offending input.
+#[derive(PartialEq, Clone, Debug)]
+pub struct Error {
+    /// Kind of failure
+    kind: ErrorKind,
+    /// Name of the function that failed
+    func: Option<&'static str>,
+    /// Input given to the function that failed
+    input: Option<String>
 }
+
+impl Error {
+    /// Create a new Error without context
+    #[inline]
+    pub const fn new(kind: ErrorKind) -> Self {
+        Self { kind, func: None, input: None }
+    }
+
+    /// Get kind property
+    #[inline]
+    pub const fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// Get func property
+    #[inline]
+    pub fn func(&self) -> Option<&'static str> {
+        self.func
+    }
+
+    /// Get input property
+    #[inline]
+    pub fn input(&self) -> Option<&str> {
+        self.input.as_deref()
+    }
+
+    /// Attach the failing function and its input.
+    /// The outermost context wins, so the error names the function the caller invoked.
+    #[inline]
+    pub fn context(self, func: &'static str, input: impl fmt::Display) -> Self {
+        Self { kind: self.kind, func: Some(func), input: Some(input.to_string()) }
+    }
+}
+
+impl From<ErrorKind> for Error {
+    #[inline]
+    fn from(kind: ErrorKind) -> Self {
+        Self::new(kind)
+    }
+}
+
+//##########################################################################################################################
+
+impl fmt::Display for Error {
+    /// Format string
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (self.func, &self.input) {
+            (Some(func), Some(input)) => write!(f, "{}({}): {}", func, input, self.kind),
+            (Some(func), None)        => write!(f, "{}: {}", func, self.kind),
+            _                         => write!(f, "{}", self.kind),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+//##########################################################################################################################
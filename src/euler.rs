@@ -10,10 +10,10 @@ use crate::constants::{ LN_LOWER_BD, LN_LOWER_MUL, LN_LOWER_VAL };
 use crate::constants::{ LN_UPPER_BD_P, LN_UPPER_MUL_P, LN_UPPER_VAL_P };
 use crate::constants::{ LN_LOWER_BD_P, LN_LOWER_MUL_P, LN_LOWER_VAL_P };
 
-use crate::error::Error;
+use crate::error::{ Error, ErrorKind };
 
 use crate::factorial::{ m_fac };
-use crate::basic::{ dec, d_pow, da_pow, };
+use crate::basic::{ Terms, dec, d_pow, da_pow, d_series };
 
 use crate::multiplex::types::{ Multiplex };
 use crate::multiplex::basic::{ ma_pow };
@@ -34,10 +34,10 @@ fn d_exp_prepare(
     value: Decimal
 ) -> Result<(Decimal, Decimal), Error> {
     let mut rem: Decimal = value.abs().fract();
-    let fract_pow: usize = (rem * D5).floor().to_usize().ok_or(Error::OptionInvalid)?;
-    let int_pow: usize = value.abs().floor().to_usize().ok_or(Error::OptionInvalid)?;
-    let base: Decimal = d_pow(E, int_pow)? * d_pow(E_POW1DIV5, fract_pow)?;
-    rem = rem - (D1DIV5 * dec(fract_pow));
+    let fract_pow: usize = (rem * D5).floor().to_usize().ok_or(ErrorKind::OptionInvalid)?;
+    let int_pow: usize = value.abs().floor().to_usize().ok_or(ErrorKind::OptionInvalid)?;
+    let base: Decimal = d_pow(E, int_pow)?.checked_mul(d_pow(E_POW1DIV5, fract_pow)?).ok_or(ErrorKind::MultiplyOverflow)?;
+    rem -= D1DIV5 * dec(fract_pow);
     Ok((rem, base))
 }
 
@@ -47,43 +47,73 @@ fn d_exp_prepare(
 #[inline]
 fn d_exp_series(
     value: Decimal,
-    terms: usize
-) -> Result<Decimal, Error> {
+    terms: Terms
+) -> Result<(Decimal, usize), Error> {
     let mut acc1: (Multiplex, usize) = (Multiplex::new(), 0);
     // Iterate over Series
-    Ok(
-        D1 + (
-            (1..=terms).into_iter()
-                .map(|n| Ok(
-                    (ma_pow(value, n, &mut acc1)? / m_fac(n)?).squash()?
-                ))
-                .reduce(|u, d| Ok(
-                    u?.checked_add(d?).ok_or(Error::AddOverflow)?
-                ))
-                .unwrap_or(Ok(D0))
-        )?
-    )
+    let (sum, used) = d_series(1, terms, |n|
+        (ma_pow(value, n, &mut acc1)? / m_fac(n)?).squash()
+    )?;
+    Ok((D1 + sum, used))
 }
 
 //##########################################################################################################################
 
 #[inline]
-pub fn d_exp(
+fn d_exp_positive(
     value: Decimal,
-    terms: usize
-) -> Result<Decimal, Error> {
+    terms: Terms
+) -> Result<(Decimal, usize), Error> {
+    let (rem, base) = d_exp_prepare(value)?;
+    let (res, used) = if rem == D0 {(D1, 0)} else { d_exp_series(rem, terms)? };
+    Ok((base.checked_mul(res).ok_or(ErrorKind::MultiplyOverflow)?, used))
+}
+
+#[inline]
+pub(crate) fn d_exp_helper(
+    value: Decimal,
+    terms: Terms
+) -> Result<(Decimal, usize), Error> {
     Ok(
-             if value ==  D0 { D1     }
-        else if value ==  D1 { E      }
-        else if value == -D1 { D1DIVE }
+             if value ==  D0 { (D1,     0) }
+        else if value ==  D1 { (E,      0) }
+        else if value == -D1 { (D1DIVE, 0) }
         else {
-            let (rem, base) = d_exp_prepare(value)?;
-            let res = base * if rem == D0 {D1} else { d_exp_series(rem, terms)? };
-            if value.is_sign_negative() {D1 / res} else {res}
+            let (res, used) = match (d_exp_positive(value.abs(), terms), value.is_sign_negative()) {
+                // e^-x falls below the scale of a Decimal where e^x overflows
+                (Err(e), true) if e.kind() == ErrorKind::MultiplyOverflow => return Ok((D0, 0)),
+                (Err(e), true) if e.kind() == ErrorKind::AddOverflow      => return Ok((D0, 0)),
+                (res, _) => res?,
+            };
+            let res =
+                if value.is_sign_negative() { D1 / res }
+                else { res };
+            (res, used)
         }
     )
 }
 
+#[inline]
+pub fn d_exp(
+    value: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    d_exp_helper(value, Terms::Fixed(terms))
+        .map(|(res, _)| res)
+        .map_err(|e| e.context("d_exp", value))
+}
+
+/// e^x to `digits` decimal places, with the number of series terms used.
+#[inline]
+pub fn d_exp_dp(
+    value: Decimal,
+    digits: u32
+) -> Result<(Decimal, usize), Error> {
+    if digits > 28 { Err(Error::from(ErrorKind::InputOutOfRange).context("d_exp_dp", digits))? };
+    d_exp_helper(value, Terms::Digits(digits))
+        .map_err(|e| e.context("d_exp_dp", value))
+}
+
 //##########################################################################################################################
 
 #[inline]
@@ -94,34 +124,34 @@ fn d_ln_prepare(
     let mut base: Decimal = D0;
     loop {
         if rem > E_SQR {
-            rem = rem / E;
-            base = base + D1;
+            rem /= E;
+            base += D1;
         }
         else if rem < D1 {
-            rem = rem * E;
-            base = base - D1;
+            rem *= E;
+            base -= D1;
         }
         else {break}
     };
     loop {
         if rem > LN_UPPER_BD {
-            rem = rem / LN_UPPER_MUL;
-            base = base + LN_UPPER_VAL;
+            rem /= LN_UPPER_MUL;
+            base += LN_UPPER_VAL;
         }
         else if rem < LN_LOWER_BD {
-            rem = rem / LN_LOWER_MUL;
-            base = base + LN_LOWER_VAL;
+            rem /= LN_LOWER_MUL;
+            base += LN_LOWER_VAL;
         }
         else {break}
     };
     loop {
         if rem > LN_UPPER_BD_P {
-            rem = rem / LN_UPPER_MUL_P;
-            base = base + LN_UPPER_VAL_P;
+            rem /= LN_UPPER_MUL_P;
+            base += LN_UPPER_VAL_P;
         }
         else if rem < LN_LOWER_BD_P {
-            rem = rem / LN_LOWER_MUL_P;
-            base = base + LN_LOWER_VAL_P;
+            rem /= LN_LOWER_MUL_P;
+            base += LN_LOWER_VAL_P;
         }
         else {break}
     };
@@ -134,45 +164,64 @@ fn d_ln_prepare(
 #[inline]
 fn d_ln_series(
     value: Decimal,
-    terms: usize
-) -> Result<Decimal, Error> {
+    terms: Terms
+) -> Result<(Decimal, usize), Error> {
     let mut acc1: (Decimal, usize) = (D1, 0);
     let mut acc2: (Multiplex, usize) = (Multiplex::new(), 0);
     let mut acc3: (Multiplex, usize) = (Multiplex::new(), 0);
     // Iterate over Series
-    Ok(
-        D1 + (
-            (1..=terms).into_iter()
-                .map(|n| Ok(
-                    da_pow(-D1, n + 1, &mut acc1)? * (
-                        ma_pow(value - E, n, &mut acc2)? / (dec(n) * ma_pow(E, n, &mut acc3)?)
-                    ).squash()?
-                ))
-                .reduce(|u, d| Ok(
-                    u?.checked_add(d?).ok_or(Error::AddOverflow)?
-                ))
-                .unwrap_or(Ok(D0))
-        )?
-    )
+    let (sum, used) = d_series(1, terms, |n| Ok(
+        da_pow(-D1, n + 1, &mut acc1)? * (
+            ma_pow(value - E, n, &mut acc2)? / (dec(n) * ma_pow(E, n, &mut acc3)?)
+        ).squash()?
+    ))?;
+    Ok((D1 + sum, used))
 }
 
 //##########################################################################################################################
 
 #[inline]
-pub fn d_ln(
+pub(crate) fn d_ln_helper(
     value: Decimal,
-    terms: usize
-) -> Result<Decimal, Error> {
-    if value <= D0 { Err(Error::InputOutOfRange)? };
+    terms: Terms
+) -> Result<(Decimal, usize), Error> {
+    if value <= D0 { Err(ErrorKind::InputOutOfRange)? };
     Ok(
-             if value == D1     {  D0 }
-        else if value == E      {  D1 }
-        else if value == D1DIVE { -D1 }
+             if value == D1     { ( D0, 0) }
+        else if value == E      { ( D1, 0) }
+        else if value == D1DIVE { (-D1, 0) }
+        else if value < D1 {
+            // ln(x) = -ln(1/x) keeps the digits of small x
+            let (res, used) = d_ln_helper(D1 / value, terms)?;
+            (-res, used)
+        }
         else {
             let (rem, base) = d_ln_prepare(value);
-            base + d_ln_series(rem, terms)?
+            let (res, used) = d_ln_series(rem, terms)?;
+            (base + res, used)
         }
     )
 }
 
+#[inline]
+pub fn d_ln(
+    value: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    d_ln_helper(value, Terms::Fixed(terms))
+        .map(|(res, _)| res)
+        .map_err(|e| e.context("d_ln", value))
+}
+
+/// ln(x) to `digits` decimal places, with the number of series terms used.
+#[inline]
+pub fn d_ln_dp(
+    value: Decimal,
+    digits: u32
+) -> Result<(Decimal, usize), Error> {
+    if digits > 28 { Err(Error::from(ErrorKind::InputOutOfRange).context("d_ln_dp", digits))? };
+    d_ln_helper(value, Terms::Digits(digits))
+        .map_err(|e| e.context("d_ln_dp", value))
+}
+
 //##########################################################################################################################
@@ -4,7 +4,7 @@ use rust_decimal::prelude::*;
 use rust_decimal_macros::dec;
 
 // Modules
-use crate::error::Error;
+use crate::error::{ Error, ErrorKind };
 use crate::multiplex::types::{ Multiplex };
 
 //##########################################################################################################################
@@ -294,7 +294,7 @@ const LFAC: [Decimal; 274] = [
 
 #[inline]
 pub fn d_fac(value: usize) -> Result<Decimal, Error> {
-    if value > 27 { Err(Error::InputOutOfRange)? };
+    if value > 27 { Err(Error::from(ErrorKind::InputOutOfRange).context("d_fac", value))? };
     Ok(LFAC[value])
 }
 
@@ -302,7 +302,7 @@ pub fn d_fac(value: usize) -> Result<Decimal, Error> {
 
 #[inline]
 pub fn m_fac(value: usize) -> Result<Multiplex, Error> {
-    if value > 255 { Err(Error::InputOutOfRange)? };
+    if value > 255 { Err(Error::from(ErrorKind::InputOutOfRange).context("m_fac", value))? };
     // Set Variables
     let mut result = Multiplex::new();
     let mut acc: usize = 0;
@@ -322,7 +322,7 @@ pub fn m_fac(value: usize) -> Result<Multiplex, Error> {
             result.mul.push(LFAC[index]);
         };
         acc = next;
-        i = i + 1;
+        i += 1;
     };
     // Return result
     Ok(result)
@@ -0,0 +1,479 @@
+// Imports
+use rust_decimal::prelude::*;
+use rust_decimal_macros::dec;
+
+// Modules
+use crate::constants::{ LN_2 };
+
+use crate::error::{ Error, ErrorKind };
+use crate::factorial::{ m_fac };
+use crate::sqrt::{ d_sqrt_helper };
+use crate::euler::{ d_exp_helper, d_ln_helper };
+use crate::basic::{ Terms, dec, da_pow, d_series };
+
+use crate::multiplex::types::{ Multiplex };
+use crate::multiplex::basic::{ m_pow, ma_pow };
+
+//##########################################################################################################################
+
+// Constants
+const D0: Decimal = Decimal::ZERO;
+const D1: Decimal = Decimal::ONE;
+const D2: Decimal = Decimal::TWO;
+
+// Beyond this bound tanh(x) rounds to 1 and sech(x) to 0
+const TANH_UPPER_BD: Decimal = dec!(33);
+const SECH_UPPER_BD: Decimal = dec!(66);
+
+// Below this bound the inverse functions use their own series
+const ASINH_LOWER_BD: Decimal = dec!(0.1);
+// Above this bound sqrt(x^2 + 1) rounds to |x|
+const ASINH_UPPER_BD: Decimal = dec!(100000000000000);
+
+const D4: Decimal = dec!(4);
+
+//##########################################################################################################################
+
+/// cosh(x) = sum(n=0; x^2n / 2n!)
+#[inline]
+fn cosh_series(
+    value: Decimal,
+    terms: Terms
+) -> Result<(Decimal, usize), Error> {
+    let mut acc1: (Multiplex, usize) = (Multiplex::new(), 0);
+    // Iterate over Series
+    d_series(0, terms, |n|
+        (ma_pow(value, 2 * n, &mut acc1)? / m_fac(2 * n)?).squash()
+    )
+}
+
+//##########################################################################################################################
+
+/// sinh(x) = sum(n=0; x^(2n + 1) / (2n + 1)!)
+#[inline]
+fn sinh_series(
+    value: Decimal,
+    terms: Terms
+) -> Result<(Decimal, usize), Error> {
+    let mut acc1: (Multiplex, usize) = (Multiplex::new(), 0);
+    // Iterate over Series
+    d_series(0, terms, |n|
+        (ma_pow(value, (2 * n) + 1, &mut acc1)? / m_fac((2 * n) + 1)?).squash()
+    )
+}
+
+//##########################################################################################################################
+
+/// (e^|x|, e^-|x|)
+#[inline]
+fn exp_pair(
+    value: Decimal,
+    terms: Terms
+) -> Result<((Decimal, Decimal), usize), Error> {
+    let (ex, used) = d_exp_helper(value.abs(), terms)?;
+    Ok(((ex, D1.checked_div(ex).ok_or(ErrorKind::DivisionByZero)?), used))
+}
+
+//##########################################################################################################################
+
+#[inline]
+fn d_sinh_helper(
+    value: Decimal,
+    terms: Terms
+) -> Result<(Decimal, usize), Error> {
+    Ok(
+             if value == D0     { (D0, 0) }
+        else if value.abs() < D1 { sinh_series(value, terms)? }
+        else {
+            let ((ex, exn), used) = exp_pair(value, terms)?;
+            let res = (ex - exn) / D2;
+            (if value.is_sign_negative() {-res} else {res}, used)
+        }
+    )
+}
+
+/// sinh(x) = (e^x - e^-x) / 2
+#[inline]
+pub fn d_sinh(
+    value: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    d_sinh_helper(value, Terms::Fixed(terms))
+        .map(|(res, _)| res)
+        .map_err(|e| e.context("d_sinh", value))
+}
+
+/// sinh(x) to `digits` decimal places, with the number of series terms used.
+#[inline]
+pub fn d_sinh_dp(
+    value: Decimal,
+    digits: u32
+) -> Result<(Decimal, usize), Error> {
+    if digits > 28 { Err(Error::from(ErrorKind::InputOutOfRange).context("d_sinh_dp", digits))? };
+    d_sinh_helper(value, Terms::Digits(digits))
+        .map_err(|e| e.context("d_sinh_dp", value))
+}
+
+//##########################################################################################################################
+
+#[inline]
+fn d_cosh_helper(
+    value: Decimal,
+    terms: Terms
+) -> Result<(Decimal, usize), Error> {
+    Ok(
+             if value == D0     { (D1, 0) }
+        else if value.abs() < D1 { cosh_series(value, terms)? }
+        else {
+            let ((ex, exn), used) = exp_pair(value, terms)?;
+            ((ex + exn) / D2, used)
+        }
+    )
+}
+
+/// cosh(x) = (e^x + e^-x) / 2
+#[inline]
+pub fn d_cosh(
+    value: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    d_cosh_helper(value, Terms::Fixed(terms))
+        .map(|(res, _)| res)
+        .map_err(|e| e.context("d_cosh", value))
+}
+
+/// cosh(x) to `digits` decimal places, with the number of series terms used.
+#[inline]
+pub fn d_cosh_dp(
+    value: Decimal,
+    digits: u32
+) -> Result<(Decimal, usize), Error> {
+    if digits > 28 { Err(Error::from(ErrorKind::InputOutOfRange).context("d_cosh_dp", digits))? };
+    d_cosh_helper(value, Terms::Digits(digits))
+        .map_err(|e| e.context("d_cosh_dp", value))
+}
+
+//##########################################################################################################################
+
+#[inline]
+fn d_tanh_helper(
+    value: Decimal,
+    terms: Terms
+) -> Result<(Decimal, usize), Error> {
+    Ok(
+             if value == D0                 { ( D0, 0) }
+        else if value >=  TANH_UPPER_BD     { ( D1, 0) }
+        else if value <= -TANH_UPPER_BD     { (-D1, 0) }
+        else if value.abs() < D1 {
+            let (_sinh, used1) = sinh_series(value, terms)?;
+            let (_cosh, used2) = cosh_series(value, terms)?;
+            (_sinh / _cosh, used1 + used2)
+        }
+        else {
+            let ((_, exn), used) = exp_pair(D2 * value, terms)?;
+            let res = (D1 - exn) / (D1 + exn);
+            (if value.is_sign_negative() {-res} else {res}, used)
+        }
+    )
+}
+
+/// tanh(x) = sinh(x) / cosh(x) = (1 - e^-2x) / (1 + e^-2x)
+#[inline]
+pub fn d_tanh(
+    value: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    d_tanh_helper(value, Terms::Fixed(terms))
+        .map(|(res, _)| res)
+        .map_err(|e| e.context("d_tanh", value))
+}
+
+/// tanh(x) to `digits` decimal places, with the number of series terms used.
+#[inline]
+pub fn d_tanh_dp(
+    value: Decimal,
+    digits: u32
+) -> Result<(Decimal, usize), Error> {
+    if digits > 28 { Err(Error::from(ErrorKind::InputOutOfRange).context("d_tanh_dp", digits))? };
+    d_tanh_helper(value, Terms::Digits(digits))
+        .map_err(|e| e.context("d_tanh_dp", value))
+}
+
+//##########################################################################################################################
+
+#[inline]
+fn d_coth_helper(
+    value: Decimal,
+    terms: Terms
+) -> Result<(Decimal, usize), Error> {
+    if value == D0 { Err(ErrorKind::Pole)? };
+    let (res, used) = d_tanh_helper(value, terms)?;
+    Ok((D1.checked_div(res).ok_or(ErrorKind::MultiplyOverflow)?, used))
+}
+
+/// coth(x) = 1 / tanh(x)
+#[inline]
+pub fn d_coth(
+    value: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    d_coth_helper(value, Terms::Fixed(terms))
+        .map(|(res, _)| res)
+        .map_err(|e| e.context("d_coth", value))
+}
+
+/// coth(x) to `digits` decimal places, with the number of series terms used.
+#[inline]
+pub fn d_coth_dp(
+    value: Decimal,
+    digits: u32
+) -> Result<(Decimal, usize), Error> {
+    if digits > 28 { Err(Error::from(ErrorKind::InputOutOfRange).context("d_coth_dp", digits))? };
+    d_coth_helper(value, Terms::Digits(digits))
+        .map_err(|e| e.context("d_coth_dp", value))
+}
+
+//##########################################################################################################################
+
+#[inline]
+fn d_sech_helper(
+    value: Decimal,
+    terms: Terms
+) -> Result<(Decimal, usize), Error> {
+    if value.abs() >= SECH_UPPER_BD { return Ok((D0, 0)) };
+    let (res, used) = d_cosh_helper(value, terms)?;
+    Ok((D1 / res, used))
+}
+
+/// sech(x) = 1 / cosh(x) = 2 / (e^x + e^-x)
+#[inline]
+pub fn d_sech(
+    value: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    d_sech_helper(value, Terms::Fixed(terms))
+        .map(|(res, _)| res)
+        .map_err(|e| e.context("d_sech", value))
+}
+
+/// sech(x) to `digits` decimal places, with the number of series terms used.
+#[inline]
+pub fn d_sech_dp(
+    value: Decimal,
+    digits: u32
+) -> Result<(Decimal, usize), Error> {
+    if digits > 28 { Err(Error::from(ErrorKind::InputOutOfRange).context("d_sech_dp", digits))? };
+    d_sech_helper(value, Terms::Digits(digits))
+        .map_err(|e| e.context("d_sech_dp", value))
+}
+
+//##########################################################################################################################
+
+#[inline]
+fn d_csch_helper(
+    value: Decimal,
+    terms: Terms
+) -> Result<(Decimal, usize), Error> {
+    if value == D0 { Err(ErrorKind::Pole)? };
+    if value.abs() >= SECH_UPPER_BD { return Ok((D0, 0)) };
+    let (res, used) = d_sinh_helper(value, terms)?;
+    Ok((D1.checked_div(res).ok_or(ErrorKind::MultiplyOverflow)?, used))
+}
+
+/// csch(x) = 1 / sinh(x) = 2 / (e^x - e^-x)
+#[inline]
+pub fn d_csch(
+    value: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    d_csch_helper(value, Terms::Fixed(terms))
+        .map(|(res, _)| res)
+        .map_err(|e| e.context("d_csch", value))
+}
+
+/// csch(x) to `digits` decimal places, with the number of series terms used.
+#[inline]
+pub fn d_csch_dp(
+    value: Decimal,
+    digits: u32
+) -> Result<(Decimal, usize), Error> {
+    if digits > 28 { Err(Error::from(ErrorKind::InputOutOfRange).context("d_csch_dp", digits))? };
+    d_csch_helper(value, Terms::Digits(digits))
+        .map_err(|e| e.context("d_csch_dp", value))
+}
+
+//##########################################################################################################################
+
+/// asinh(x) = sum(n=0; -1^n * (2n! * x^(2n + 1)) / (4^n * n!^2 * (2n + 1)))
+#[inline]
+fn asinh_series(
+    value: Decimal,
+    terms: Terms
+) -> Result<(Decimal, usize), Error> {
+    let mut acc1: (Decimal, usize) = (D1, 0);
+    let mut acc2: (Multiplex, usize) = (Multiplex::new(), 0);
+    let mut acc3: (Multiplex, usize) = (Multiplex::new(), 0);
+    // Iterate over Series
+    d_series(0, terms, |n| Ok(
+        da_pow(-D1, n, &mut acc1)? * (
+            (ma_pow(value, (2 * n) + 1, &mut acc2)? * m_fac(2 * n)?) / (
+                ma_pow(D4, n, &mut acc3)? *
+                m_pow(m_fac(n)?, 2)? *
+                ((D2 * dec(n)) + D1)
+            )
+        ).squash()?
+    ))
+}
+
+//##########################################################################################################################
+
+/// atanh(x) = sum(n=0; x^(2n + 1) / (2n + 1))
+#[inline]
+fn atanh_series(
+    value: Decimal,
+    terms: Terms
+) -> Result<(Decimal, usize), Error> {
+    let mut acc1: (Multiplex, usize) = (Multiplex::new(), 0);
+    // Iterate over Series
+    d_series(0, terms, |n|
+        (ma_pow(value, (2 * n) + 1, &mut acc1)? / ((D2 * dec(n)) + D1)).squash()
+    )
+}
+
+//##########################################################################################################################
+
+#[inline]
+fn d_asinh_helper(
+    value: Decimal,
+    terms: Terms
+) -> Result<(Decimal, usize), Error> {
+    let abs = value.abs();
+    let (res, used) =
+             if value == D0             { (D0, 0) }
+        else if abs < ASINH_LOWER_BD    { asinh_series(abs, terms)? }
+        else if abs > ASINH_UPPER_BD    {
+            let (res, used) = d_ln_helper(abs, terms)?;
+            (res + LN_2, used)
+        }
+        else {
+            let (root, used1) = d_sqrt_helper((abs * abs) + D1, terms)?;
+            let (res, used2) = d_ln_helper(abs + root, terms)?;
+            (res, used1 + used2)
+        };
+    Ok((if value.is_sign_negative() {-res} else {res}, used))
+}
+
+/// asinh(x) = ln(x + sqrt(x^2 + 1))
+#[inline]
+pub fn d_asinh(
+    value: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    d_asinh_helper(value, Terms::Fixed(terms))
+        .map(|(res, _)| res)
+        .map_err(|e| e.context("d_asinh", value))
+}
+
+/// asinh(x) to `digits` decimal places, with the number of series terms used.
+#[inline]
+pub fn d_asinh_dp(
+    value: Decimal,
+    digits: u32
+) -> Result<(Decimal, usize), Error> {
+    if digits > 28 { Err(Error::from(ErrorKind::InputOutOfRange).context("d_asinh_dp", digits))? };
+    d_asinh_helper(value, Terms::Digits(digits))
+        .map_err(|e| e.context("d_asinh_dp", value))
+}
+
+//##########################################################################################################################
+
+#[inline]
+fn d_acosh_helper(
+    value: Decimal,
+    terms: Terms
+) -> Result<(Decimal, usize), Error> {
+    if value < D1 { Err(ErrorKind::InputOutOfRange)? };
+    Ok(
+             if value == D1             { (D0, 0) }
+        else if value < D2              {
+            let (root, used1) = d_sqrt_helper((value - D1) / D2, terms)?;
+            let (res, used2) = d_asinh_helper(root, terms)?;
+            (D2 * res, used1 + used2)
+        }
+        else if value > ASINH_UPPER_BD  {
+            let (res, used) = d_ln_helper(value, terms)?;
+            (res + LN_2, used)
+        }
+        else {
+            let (root, used1) = d_sqrt_helper((value * value) - D1, terms)?;
+            let (res, used2) = d_ln_helper(value + root, terms)?;
+            (res, used1 + used2)
+        }
+    )
+}
+
+/// acosh(x) = ln(x + sqrt(x^2 - 1)) = 2 * asinh(sqrt((x - 1) / 2))
+#[inline]
+pub fn d_acosh(
+    value: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    d_acosh_helper(value, Terms::Fixed(terms))
+        .map(|(res, _)| res)
+        .map_err(|e| e.context("d_acosh", value))
+}
+
+/// acosh(x) to `digits` decimal places, with the number of series terms used.
+#[inline]
+pub fn d_acosh_dp(
+    value: Decimal,
+    digits: u32
+) -> Result<(Decimal, usize), Error> {
+    if digits > 28 { Err(Error::from(ErrorKind::InputOutOfRange).context("d_acosh_dp", digits))? };
+    d_acosh_helper(value, Terms::Digits(digits))
+        .map_err(|e| e.context("d_acosh_dp", value))
+}
+
+//##########################################################################################################################
+
+#[inline]
+fn d_atanh_helper(
+    value: Decimal,
+    terms: Terms
+) -> Result<(Decimal, usize), Error> {
+    if value.abs() > D1 { Err(ErrorKind::InputOutOfRange)? };
+    if value.abs() == D1 { Err(ErrorKind::Pole)? };
+    let abs = value.abs();
+    let (res, used) =
+             if value == D0             { (D0, 0) }
+        else if abs < ASINH_LOWER_BD    { atanh_series(abs, terms)? }
+        else {
+            let (res, used) = d_ln_helper((D1 + abs) / (D1 - abs), terms)?;
+            (res / D2, used)
+        };
+    Ok((if value.is_sign_negative() {-res} else {res}, used))
+}
+
+/// atanh(x) = ln((1 + x) / (1 - x)) / 2
+#[inline]
+pub fn d_atanh(
+    value: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    d_atanh_helper(value, Terms::Fixed(terms))
+        .map(|(res, _)| res)
+        .map_err(|e| e.context("d_atanh", value))
+}
+
+/// atanh(x) to `digits` decimal places, with the number of series terms used.
+#[inline]
+pub fn d_atanh_dp(
+    value: Decimal,
+    digits: u32
+) -> Result<(Decimal, usize), Error> {
+    if digits > 28 { Err(Error::from(ErrorKind::InputOutOfRange).context("d_atanh_dp", digits))? };
+    d_atanh_helper(value, Terms::Digits(digits))
+        .map_err(|e| e.context("d_atanh_dp", value))
+}
+
+//##########################################################################################################################
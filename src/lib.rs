@@ -1,14 +1,16 @@
-
 pub mod error;
 pub mod constants;
 pub mod basic;
 pub mod sqrt;
 pub mod factorial;
 pub mod trigonometry;
+pub mod hyperbolic;
 pub mod euler;
 pub mod multiplex;
 pub mod complex;
-// pub mod primes;
+pub mod primes;
+pub mod number_theory;
+pub mod special;
 
 #[cfg(test)]
 mod tests;
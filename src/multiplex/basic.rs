@@ -3,7 +3,7 @@
 use rust_decimal::prelude::*;
 
 // Modules
-use crate::error::Error;
+use crate::error::{ Error, ErrorKind };
 use crate::basic::{ dec };
 
 use crate::multiplex::types::{ Multiplex };
@@ -22,10 +22,10 @@ fn m_pow_helper(
     value: Multiplex,
     power: usize
 ) -> Result<Multiplex, Error> {
-    (1..=power).into_iter()
+    (1..=power)
         .map(|_| Ok(value.clone()))
         .reduce(|u, d| Ok(u? * d?))
-        .unwrap_or(Err(Error::IteratorError))
+        .unwrap_or(Err(ErrorKind::IteratorError.into()))
 }
 
 #[inline]
@@ -6,17 +6,18 @@ use std::fmt;
 use rust_decimal::prelude::*;
 
 // Modules
-use crate::error::Error;
+use crate::error::{ Error, ErrorKind };
 
 //##########################################################################################################################
 
 // Constants
+const D0: Decimal = Decimal::ZERO;
 const D1: Decimal = Decimal::ONE;
 
 //##########################################################################################################################
 
 /// A multiplex number in iterable form. `z = a * b * c * ...`
-#[derive(PartialEq, Clone, Hash, Debug)]
+#[derive(PartialEq, Clone, Hash, Default, Debug)]
 pub struct Multiplex {
     /// Terms of the multiplex number
     pub mul: Vec<Decimal>,
@@ -81,10 +82,11 @@ impl Multiplex {
             // Apply division
             let _mul = mul.pop().unwrap_or(D1);
             let _div = div.pop().unwrap_or(D1);
-            let value = _mul.checked_div(_div).ok_or(Error::MultiplyOverflow)?;
+            if _div == D0 {break Err(ErrorKind::DivisionByZero.into())};
+            let value = _mul.checked_div(_div).ok_or(ErrorKind::MultiplyOverflow)?;
             // Check if Multiplex is empty
             if div.is_empty() && mul.is_empty() {break Ok(value)};
-            if div.is_empty() && !(s_mul || s_div) {break Err(Error::MultiplyOverflow)};
+            if div.is_empty() && !(s_mul || s_div) {break Err(ErrorKind::MultiplyOverflow.into())};
             // Setup next Iteration
             mul.push(value);
         }
@@ -111,7 +113,7 @@ impl Mul<Multiplex> for Decimal {
     #[inline]
     fn mul(self, other: Multiplex) -> Multiplex {
         let mut target = other.clone();
-        target.mul.push(self.clone());
+        target.mul.push(self);
         target
     }
 }
@@ -122,7 +124,7 @@ impl Mul<Decimal> for Multiplex {
     #[inline]
     fn mul(self, other: Decimal) -> Multiplex {
         let mut target = self.clone();
-        target.mul.push(other.clone());
+        target.mul.push(other);
         target
     }
 }
@@ -147,7 +149,7 @@ impl Div<Multiplex> for Decimal {
     #[inline]
     fn div(self, other: Multiplex) -> Multiplex {
         let mut target = Multiplex::new();
-        target.mul.push(self.clone());
+        target.mul.push(self);
         target = target / other;
         target
     }
@@ -159,7 +161,7 @@ impl Div<Decimal> for Multiplex {
     #[inline]
     fn div(self, other: Decimal) -> Multiplex {
         let mut target = self.clone();
-        target.div.push(other.clone());
+        target.div.push(other);
         target
     }
 }
@@ -186,8 +188,8 @@ impl fmt::Display for Multiplex {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let mut mul = self.mul.iter().map(|v| v.to_string()).collect::<Vec<String>>().join(" * ");
         let mut div = self.div.iter().map(|v| v.to_string()).collect::<Vec<String>>().join(" * ");
-        if mul == "" { mul = "1".to_string() };
-        if div == "" { div = "1".to_string() };
+        if mul.is_empty() { mul = "1".to_string() };
+        if div.is_empty() { div = "1".to_string() };
         write!(f, "({}) / ({})", mul, div)
     }
 }
@@ -0,0 +1,253 @@
+
+// Imports
+use rust_decimal::prelude::*;
+
+// Modules
+use crate::error::{ Error, ErrorKind };
+use crate::primes::factor::{ factorize };
+
+//##########################################################################################################################
+
+// Constants
+const D0: Decimal = Decimal::ZERO;
+
+//##########################################################################################################################
+
+/// Integral non-negative Decimal as u64
+#[inline]
+pub(crate) fn d_to_u64(
+    value: Decimal
+) -> Result<u64, Error> {
+    if (value.fract() != D0) || (value < D0) { Err(ErrorKind::InputOutOfRange)? };
+    Ok(value.to_u64().ok_or(ErrorKind::InputOutOfRange)?)
+}
+
+/// Prime factorization of n > 0
+#[inline]
+fn factors(
+    value: u64
+) -> Result<Vec<(u64, u32)>, Error> {
+    if value == 0 { Err(ErrorKind::InputOutOfRange)? };
+    factorize(value)
+}
+
+/// p^e with overflow check
+#[inline]
+fn u_pow(
+    value: u64,
+    power: u32
+) -> Result<u64, Error> {
+    Ok(value.checked_pow(power).ok_or(ErrorKind::MultiplyOverflow)?)
+}
+
+/// a * b with overflow check
+#[inline]
+fn u_mul(
+    value: u64,
+    other: u64
+) -> Result<u64, Error> {
+    Ok(value.checked_mul(other).ok_or(ErrorKind::MultiplyOverflow)?)
+}
+
+//##########################################################################################################################
+
+/// gcd(a, b) by Euclid's algorithm, with gcd(0, 0) = 0
+pub fn gcd(
+    value: u64,
+    other: u64
+) -> u64 {
+    let (mut a, mut b) = (value, other);
+    while b != 0 { (a, b) = (b, a % b); };
+    a
+}
+
+/// lcm(a, b) = a * b / gcd(a, b)
+pub fn lcm(
+    value: u64,
+    other: u64
+) -> Result<u64, Error> {
+    if (value == 0) || (other == 0) { return Ok(0) };
+    u_mul(value / gcd(value, other), other)
+        .map_err(|e| e.context("lcm", format!("{}, {}", value, other)))
+}
+
+/// (g, x, y) such that a * x + b * y = g = gcd(a, b)
+pub fn ext_gcd(
+    value: u64,
+    other: u64
+) -> (u64, i128, i128) {
+    let (mut r0, mut r1) = (value as i128, other as i128);
+    let (mut x0, mut x1) = (1_i128, 0_i128);
+    let (mut y0, mut y1) = (0_i128, 1_i128);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - (q * r1));
+        (x0, x1) = (x1, x0 - (q * x1));
+        (y0, y1) = (y1, y0 - (q * y1));
+    };
+    (r0 as u64, x0, y0)
+}
+
+//##########################################################################################################################
+
+/// φ(n) = n * prod(p | n; 1 - 1/p)
+pub fn totient(
+    value: u64
+) -> Result<u64, Error> {
+    let ctx = |e: Error| e.context("totient", value);
+    factors(value).map_err(ctx)?.into_iter()
+        .try_fold(1_u64, |acc, (p, e)| u_mul(acc, u_pow(p, e - 1)? * (p - 1)))
+        .map_err(ctx)
+}
+
+/// λ(n) = lcm(λ(p1^e1), λ(p2^e2), ...) with λ(2^e) = 2^(e-2) for e >= 3
+pub fn carmichael(
+    value: u64
+) -> Result<u64, Error> {
+    let ctx = |e: Error| e.context("carmichael", value);
+    factors(value).map_err(ctx)?.into_iter()
+        .try_fold(1_u64, |acc, (p, e)| {
+            let lambda =
+                if (p == 2) && (e >= 3) { u_pow(2, e - 2)? }
+                else { u_pow(p, e - 1)? * (p - 1) };
+            lcm(acc, lambda)
+        })
+        .map_err(ctx)
+}
+
+//##########################################################################################################################
+
+/// σ_k(n) = sum(d | n; d^k) = prod(p^e || n; 1 + p^k + p^2k + ... + p^ek)
+pub fn sigma(
+    value: u64,
+    power: u32
+) -> Result<u64, Error> {
+    let ctx = |e: Error| e.context("sigma", format!("{}, {}", value, power));
+    factors(value).map_err(ctx)?.into_iter()
+        .try_fold(1_u64, |acc, (p, e)| {
+            let pk = u_pow(p, power)?;
+            let mut term: u64 = 1;
+            let mut sum: u64 = 1;
+            for _ in 0..e {
+                term = u_mul(term, pk)?;
+                sum = sum.checked_add(term).ok_or(ErrorKind::AddOverflow)?;
+            };
+            u_mul(acc, sum)
+        })
+        .map_err(ctx)
+}
+
+/// τ(n) = σ_0(n) = prod(p^e || n; e + 1)
+pub fn tau(
+    value: u64
+) -> Result<u64, Error> {
+    Ok(
+        factors(value).map_err(|e| e.context("tau", value))?.into_iter()
+            .map(|(_, e)| (e as u64) + 1)
+            .product()
+    )
+}
+
+/// rad(n) = prod(p | n; p)
+pub fn radical(
+    value: u64
+) -> Result<u64, Error> {
+    Ok(
+        factors(value).map_err(|e| e.context("radical", value))?.into_iter()
+            .map(|(p, _)| p)
+            .product()
+    )
+}
+
+//##########################################################################################################################
+
+/// gcd(a, b) on integral Decimals, with gcd(-a, b) = gcd(a, b)
+pub fn d_gcd(
+    value: Decimal,
+    other: Decimal
+) -> Result<Decimal, Error> {
+    let ctx = |e: Error| e.context("d_gcd", format!("{}, {}", value, other));
+    let a = d_to_u64(value.abs()).map_err(ctx)?;
+    let b = d_to_u64(other.abs()).map_err(ctx)?;
+    Ok(Decimal::from(gcd(a, b)))
+}
+
+/// lcm(a, b) on integral Decimals, with lcm(-a, b) = lcm(a, b)
+pub fn d_lcm(
+    value: Decimal,
+    other: Decimal
+) -> Result<Decimal, Error> {
+    let ctx = |e: Error| e.context("d_lcm", format!("{}, {}", value, other));
+    let a = d_to_u64(value.abs()).map_err(ctx)?;
+    let b = d_to_u64(other.abs()).map_err(ctx)?;
+    Ok(Decimal::from(lcm(a, b).map_err(ctx)?))
+}
+
+/// (g, x, y) such that a * x + b * y = g = gcd(a, b) on integral Decimals, with the signs of a and b moved onto x and y
+pub fn d_ext_gcd(
+    value: Decimal,
+    other: Decimal
+) -> Result<(Decimal, Decimal, Decimal), Error> {
+    let ctx = |e: Error| e.context("d_ext_gcd", format!("{}, {}", value, other));
+    let a = d_to_u64(value.abs()).map_err(ctx)?;
+    let b = d_to_u64(other.abs()).map_err(ctx)?;
+    let (g, x, y) = ext_gcd(a, b);
+    let x = if value < D0 {-x} else {x};
+    let y = if other < D0 {-y} else {y};
+    let x = Decimal::from_i128(x).ok_or(ErrorKind::InputOutOfRange).map_err(|e| ctx(e.into()))?;
+    let y = Decimal::from_i128(y).ok_or(ErrorKind::InputOutOfRange).map_err(|e| ctx(e.into()))?;
+    Ok((Decimal::from(g), x, y))
+}
+
+//##########################################################################################################################
+
+/// Lift a u64 arithmetic function onto integral Decimals
+#[inline]
+fn d_lift(
+    value: Decimal,
+    name: &'static str,
+    func: fn(u64) -> Result<u64, Error>
+) -> Result<Decimal, Error> {
+    let n = d_to_u64(value).map_err(|e| e.context(name, value))?;
+    Ok(Decimal::from(func(n).map_err(|e| e.context(name, value))?))
+}
+
+/// φ(n) on integral Decimals
+pub fn d_totient(
+    value: Decimal
+) -> Result<Decimal, Error> {
+    d_lift(value, "d_totient", totient)
+}
+
+/// λ(n) on integral Decimals
+pub fn d_carmichael(
+    value: Decimal
+) -> Result<Decimal, Error> {
+    d_lift(value, "d_carmichael", carmichael)
+}
+
+/// τ(n) on integral Decimals
+pub fn d_tau(
+    value: Decimal
+) -> Result<Decimal, Error> {
+    d_lift(value, "d_tau", tau)
+}
+
+/// rad(n) on integral Decimals
+pub fn d_radical(
+    value: Decimal
+) -> Result<Decimal, Error> {
+    d_lift(value, "d_radical", radical)
+}
+
+/// σ_k(n) on integral Decimals
+pub fn d_sigma(
+    value: Decimal,
+    power: u32
+) -> Result<Decimal, Error> {
+    let ctx = |e: Error| e.context("d_sigma", format!("{}, {}", value, power));
+    let n = d_to_u64(value).map_err(ctx)?;
+    Ok(Decimal::from(sigma(n, power).map_err(ctx)?))
+}
+
+//##########################################################################################################################
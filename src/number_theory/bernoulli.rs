@@ -0,0 +1,174 @@
+
+// Imports
+use rust_decimal::prelude::*;
+
+// Modules
+use crate::constants::{ PI2 };
+use crate::error::{ Error, ErrorKind };
+
+use crate::basic::{ dec, d_pow };
+use crate::factorial::{ m_fac };
+
+use crate::multiplex::types::{ Multiplex };
+use crate::multiplex::basic::{ m_pow };
+
+//##########################################################################################################################
+
+// Constants
+const D0: Decimal = Decimal::ZERO;
+const D1: Decimal = Decimal::ONE;
+const D2: Decimal = Decimal::TWO;
+
+// Largest n with B_n exact in i128
+const BERNOULLI_UPPER_BD: usize = 58;
+
+// Decimal places held by the mantissa of a Decimal
+const DECIMAL_DIGITS: u32 = 28;
+
+//##########################################################################################################################
+
+/// gcd(a, b) on u128
+#[inline]
+fn u_gcd(
+    value: u128,
+    other: u128
+) -> u128 {
+    let (mut a, mut b) = (value, other);
+    while b != 0 { (a, b) = (b, a % b); };
+    a
+}
+
+/// p/q reduced, with q > 0
+#[inline]
+fn r_new(
+    num: i128,
+    den: i128
+) -> (i128, i128) {
+    let g = u_gcd(num.unsigned_abs(), den.unsigned_abs()).max(1) as i128;
+    if den < 0 {(-num / g, -den / g)} else {(num / g, den / g)}
+}
+
+/// p/q + r/s, with q, s > 0
+#[inline]
+fn r_add(
+    value: (i128, i128),
+    other: (i128, i128)
+) -> Result<(i128, i128), Error> {
+    let g = u_gcd(value.1 as u128, other.1 as u128) as i128;
+    let a = value.0.checked_mul(other.1 / g).ok_or(ErrorKind::MultiplyOverflow)?;
+    let b = other.0.checked_mul(value.1 / g).ok_or(ErrorKind::MultiplyOverflow)?;
+    let num = a.checked_add(b).ok_or(ErrorKind::AddOverflow)?;
+    let den = value.1.checked_mul(other.1 / g).ok_or(ErrorKind::MultiplyOverflow)?;
+    Ok(r_new(num, den))
+}
+
+/// k * p/q, with q > 0
+#[inline]
+fn r_scale(
+    value: (i128, i128),
+    scale: i128
+) -> Result<(i128, i128), Error> {
+    let (num, den) = r_new(scale, value.1);
+    Ok((value.0.checked_mul(num).ok_or(ErrorKind::MultiplyOverflow)?, den))
+}
+
+//##########################################################################################################################
+
+/// B_0..=B_n by sum(k=0; m; C(m + 1, k) * B_k) = 0, with B_1 = -1/2
+#[inline]
+fn bernoulli_table(
+    value: usize
+) -> Result<Vec<(i128, i128)>, Error> {
+    // Set Variables
+    let mut table: Vec<(i128, i128)> = vec![(1, 1)];
+    let mut row: Vec<i128> = vec![1, 1];
+    for m in 1..=value {
+        // Pascal's row for m + 1
+        row = (0..=(m + 1))
+            .map(|k| if (k == 0) || (k == (m + 1)) {1} else {row[k - 1] + row[k]})
+            .collect();
+        // B_m is zero for odd m > 1
+        if (m > 1) && (m % 2 != 0) {
+            table.push((0, 1));
+            continue;
+        };
+        let sum = table.iter().enumerate()
+            .filter(|&(_, b)| b.0 != 0)
+            .try_fold((0, 1), |acc, (k, &b)| r_add(acc, r_scale(b, row[k])?))?;
+        table.push(r_new(-sum.0, sum.1.checked_mul((m + 1) as i128).ok_or(ErrorKind::MultiplyOverflow)?));
+    };
+    Ok(table)
+}
+
+/// B_n = p/q exactly, as (p, q) with q > 0, for n <= 58
+pub fn bernoulli(
+    value: usize
+) -> Result<(i128, i128), Error> {
+    let ctx = |e: Error| e.context("bernoulli", value);
+    if value > BERNOULLI_UPPER_BD { Err(ctx(ErrorKind::InputOutOfRange.into()))? };
+    Ok(bernoulli_table(value).map_err(ctx)?[value])
+}
+
+//##########################################################################################################################
+
+/// B_n as a Multiplex, with the numerator cut to the precision of a Decimal
+pub fn m_bernoulli(
+    value: usize
+) -> Result<Multiplex, Error> {
+    let ctx = |e: Error| e.context("m_bernoulli", value);
+    let (num, den) = bernoulli(value).map_err(ctx)?;
+    // p ≈ p' * 10^k with p' rounded to the digits of a Decimal
+    let digits = num.unsigned_abs().checked_ilog10().unwrap_or(0) + 1;
+    let scale = 10_i128.pow(digits.saturating_sub(DECIMAL_DIGITS));
+    let half = if num < 0 {-scale / 2} else {scale / 2};
+    let lead = Decimal::from_i128((num + half) / scale).ok_or(ctx(ErrorKind::InputOutOfRange.into()))?;
+    let den = Decimal::from_i128(den).ok_or(ctx(ErrorKind::InputOutOfRange.into()))?;
+    // Leading digits go last, so squash divides them before applying the scale
+    let mut result = Multiplex::new();
+    if scale > 1 { result.mul.push(Decimal::from_i128(scale).ok_or(ctx(ErrorKind::InputOutOfRange.into()))?); };
+    Ok((result * lead) / den)
+}
+
+/// B_n as a Decimal, from the integer part and remainder of p/q
+pub fn d_bernoulli(
+    value: usize
+) -> Result<Decimal, Error> {
+    let ctx = |e: Error| e.context("d_bernoulli", value);
+    let (num, den) = bernoulli(value).map_err(ctx)?;
+    let int = Decimal::from_i128(num / den).ok_or(ctx(ErrorKind::MultiplyOverflow.into()))?;
+    let rem = Decimal::from_i128(num % den).ok_or(ctx(ErrorKind::MultiplyOverflow.into()))?;
+    let den = Decimal::from_i128(den).ok_or(ctx(ErrorKind::MultiplyOverflow.into()))?;
+    int.checked_add(rem / den).ok_or(ctx(ErrorKind::AddOverflow.into()))
+}
+
+//##########################################################################################################################
+
+/// Z(n) = (-1)^(n/2 + 1) * B_n * (2π)^n / (2 * n!) for even n
+/// Z(n) = sum(k=1; k^(-n)) past the exact Bernoulli numbers, where a few terms reach full precision
+pub fn zeta_even(
+    value: usize
+) -> Result<Decimal, Error> {
+    let ctx = |e: Error| e.context("zeta_even", value);
+    if let 1=value%2 { Err(ctx(ErrorKind::InputOutOfRange.into()))? };
+    if value > BERNOULLI_UPPER_BD {
+        // Set Variables
+        let mut sum = D1;
+        let mut k: usize = 2;
+        loop {
+            let term = d_pow(dec(k), value).map(|p| D1 / p).unwrap_or(D0);
+            if term == D0 { break Ok(sum) };
+            sum += term;
+            k += 1;
+        }
+    }
+    else {
+        let sign = if let 0=(value/2)%2 {-D1} else {D1};
+        let pow = m_pow(Multiplex::new() * PI2, value).map_err(ctx)?;
+        let fac = m_fac(value).map_err(ctx)?;
+        // B_n is squashed first, keeping every partial product large
+        let mut res = (pow * m_bernoulli(value).map_err(ctx)?) / (fac * D2) * sign;
+        res.squash().map_err(ctx)
+    }
+}
+
+//##########################################################################################################################
@@ -0,0 +1,6 @@
+pub mod arithmetic;
+pub mod modular;
+pub mod bernoulli;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,257 @@
+
+// Imports
+use rust_decimal::prelude::*;
+
+// Modules
+use crate::error::{ Error, ErrorKind };
+use crate::primes::factor::{ is_prime, factorize, mul_mod, pow_mod };
+use crate::number_theory::arithmetic::{ d_to_u64, gcd, ext_gcd, totient };
+
+//##########################################################################################################################
+
+/// a^e mod m
+pub fn mod_pow(
+    value: u64,
+    power: u64,
+    modulus: u64
+) -> Result<u64, Error> {
+    if modulus == 0 {
+        Err(Error::from(ErrorKind::DivisionByZero).context("mod_pow", format!("{}, {}, {}", value, power, modulus)))?
+    };
+    Ok(pow_mod(value, power, modulus))
+}
+
+/// a^-1 mod m, defined when gcd(a, m) = 1
+pub fn mod_inv(
+    value: u64,
+    modulus: u64
+) -> Result<u64, Error> {
+    let err = |kind: ErrorKind| Error::from(kind).context("mod_inv", format!("{}, {}", value, modulus));
+    if modulus == 0 { Err(err(ErrorKind::DivisionByZero))? };
+    let (g, x, _) = ext_gcd(value % modulus, modulus);
+    if g != 1 { Err(err(ErrorKind::DomainError))? };
+    Ok(x.rem_euclid(modulus as i128) as u64)
+}
+
+//##########################################################################################################################
+
+/// x ≡ r_i (mod m_i) for every pair, as (x, lcm(m_i)) with 0 <= x < lcm(m_i)
+pub fn crt(
+    pairs: &[(u64, u64)]
+) -> Result<(u64, u64), Error> {
+    let ctx = |e: Error| e.context("crt", format!("{:?}", pairs));
+    let err = |kind: ErrorKind| ctx(kind.into());
+    // Set Variables
+    let mut r: u128 = 0;
+    let mut m: u128 = 1;
+    // Merge congruences one at a time
+    for &(r2, m2) in pairs {
+        if m2 == 0 { Err(err(ErrorKind::DivisionByZero))? };
+        let (r2, m2) = ((r2 % m2) as u128, m2 as u128);
+        let g = gcd(m as u64, m2 as u64) as u128;
+        let diff = ((r2 + m2) - (r % m2)) % m2;
+        if !diff.is_multiple_of(g) { Err(err(ErrorKind::DomainError))? };
+        let step = m2 / g;
+        let lcm = m * step;
+        if lcm > (u64::MAX as u128) { Err(err(ErrorKind::MultiplyOverflow))? };
+        // r + m * k ≡ r2 (mod m2) => k ≡ (diff / g) * (m / g)^-1 (mod m2 / g)
+        let inv = if step == 1 {0} else { mod_inv(((m / g) % step) as u64, step as u64).map_err(ctx)? as u128 };
+        let k = mul_mod(((diff / g) % step) as u64, inv as u64, step as u64) as u128;
+        r = (r + (m * k)) % lcm;
+        m = lcm;
+    };
+    Ok((r as u64, m as u64))
+}
+
+//##########################################################################################################################
+
+/// (a/p) = a^((p-1)/2) mod p for an odd prime p
+pub fn legendre(
+    value: u64,
+    modulus: u64
+) -> Result<i8, Error> {
+    if (modulus == 2) || !is_prime(modulus) {
+        Err(Error::from(ErrorKind::InputOutOfRange).context("legendre", format!("{}, {}", value, modulus)))?
+    };
+    let res = pow_mod(value, (modulus - 1) / 2, modulus);
+    Ok(
+             if res == 0 { 0 }
+        else if res == 1 { 1 }
+        else             { -1 }
+    )
+}
+
+/// (a/n) for an odd n > 0, by quadratic reciprocity
+pub fn jacobi(
+    value: u64,
+    modulus: u64
+) -> Result<i8, Error> {
+    if let 0=modulus%2 {
+        Err(Error::from(ErrorKind::InputOutOfRange).context("jacobi", format!("{}, {}", value, modulus)))?
+    };
+    // Set Variables
+    let mut a = value % modulus;
+    let mut n = modulus;
+    let mut result: i8 = 1;
+    while a != 0 {
+        // (2/n) = -1 when n ≡ 3, 5 (mod 8)
+        while let 0=a%2 {
+            a /= 2;
+            if ((n % 8) == 3) || ((n % 8) == 5) { result = -result; };
+        };
+        // (a/n) = -(n/a) when a ≡ n ≡ 3 (mod 4)
+        (a, n) = (n, a);
+        if ((a % 4) == 3) && ((n % 4) == 3) { result = -result; };
+        a %= n;
+    };
+    Ok(if n == 1 {result} else {0})
+}
+
+//##########################################################################################################################
+
+/// Smallest x with x^2 ≡ a (mod p) for a prime p, by Tonelli-Shanks
+pub fn sqrt_mod(
+    value: u64,
+    modulus: u64
+) -> Result<u64, Error> {
+    let err = |kind: ErrorKind| Error::from(kind).context("sqrt_mod", format!("{}, {}", value, modulus));
+    if !is_prime(modulus) { Err(err(ErrorKind::InputOutOfRange))? };
+    let a = value % modulus;
+    if (a == 0) || (modulus == 2) { return Ok(a) };
+    if legendre(a, modulus)? != 1 { Err(err(ErrorKind::DomainError))? };
+    // p - 1 = q * 2^s
+    let s = (modulus - 1).trailing_zeros();
+    let q = (modulus - 1) >> s;
+    // First quadratic non-residue
+    let mut z: u64 = 2;
+    while legendre(z, modulus)? != -1 { z += 1; };
+    // Set Variables
+    let mut m = s;
+    let mut c = pow_mod(z, q, modulus);
+    let mut t = pow_mod(a, q, modulus);
+    let mut r = pow_mod(a, q.div_ceil(2), modulus);
+    while t != 1 {
+        // Least i with t^(2^i) = 1
+        let mut i: u32 = 0;
+        let mut t2 = t;
+        while t2 != 1 {
+            t2 = mul_mod(t2, t2, modulus);
+            i += 1;
+        };
+        let b = pow_mod(c, 1 << (m - i - 1), modulus);
+        m = i;
+        c = mul_mod(b, b, modulus);
+        t = mul_mod(t, c, modulus);
+        r = mul_mod(r, b, modulus);
+    };
+    Ok(if r > (modulus - r) {modulus - r} else {r})
+}
+
+//##########################################################################################################################
+
+/// Smallest generator of (Z/nZ)*, which exists for n = 1, 2, 4, p^k and 2p^k
+pub fn primitive_root(
+    value: u64
+) -> Result<u64, Error> {
+    let ctx = |e: Error| e.context("primitive_root", value);
+    if value == 0 { Err(ctx(ErrorKind::InputOutOfRange.into()))? };
+    if value <= 2 { return Ok(value - 1) };
+    if value == 4 { return Ok(3) };
+    // Check n = p^k or 2p^k with p odd
+    let factors = factorize(value).map_err(ctx)?;
+    let odd: Vec<&(u64, u32)> = factors.iter().filter(|(p, _)| *p != 2).collect();
+    let twos = factors.iter().find(|(p, _)| *p == 2).map_or(0, |&(_, e)| e);
+    if (odd.len() != 1) || (twos > 1) { Err(ctx(ErrorKind::DomainError.into()))? };
+    // g is a generator when g^(φ/q) != 1 for every prime q | φ
+    let phi = totient(value).map_err(ctx)?;
+    let divisors = factorize(phi).map_err(ctx)?;
+    (2..value)
+        .find(|&g| {
+            (gcd(g, value) == 1) &&
+            divisors.iter().all(|&(q, _)| pow_mod(g, phi / q, value) != 1)
+        })
+        .ok_or(ctx(ErrorKind::NotConverged.into()))
+}
+
+//##########################################################################################################################
+
+/// a^e mod m on integral Decimals
+pub fn d_mod_pow(
+    value: Decimal,
+    power: Decimal,
+    modulus: Decimal
+) -> Result<Decimal, Error> {
+    let ctx = |e: Error| e.context("d_mod_pow", format!("{}, {}, {}", value, power, modulus));
+    let a = d_to_u64(value).map_err(ctx)?;
+    let e = d_to_u64(power).map_err(ctx)?;
+    let m = d_to_u64(modulus).map_err(ctx)?;
+    Ok(Decimal::from(mod_pow(a, e, m).map_err(ctx)?))
+}
+
+/// a^-1 mod m on integral Decimals
+pub fn d_mod_inv(
+    value: Decimal,
+    modulus: Decimal
+) -> Result<Decimal, Error> {
+    let ctx = |e: Error| e.context("d_mod_inv", format!("{}, {}", value, modulus));
+    let a = d_to_u64(value).map_err(ctx)?;
+    let m = d_to_u64(modulus).map_err(ctx)?;
+    Ok(Decimal::from(mod_inv(a, m).map_err(ctx)?))
+}
+
+/// Chinese Remainder Theorem on integral Decimals
+pub fn d_crt(
+    pairs: &[(Decimal, Decimal)]
+) -> Result<(Decimal, Decimal), Error> {
+    let ctx = |e: Error| e.context("d_crt", format!("{:?}", pairs));
+    let pairs = pairs.iter()
+        .map(|&(r, m)| Ok((d_to_u64(r)?, d_to_u64(m)?)))
+        .collect::<Result<Vec<(u64, u64)>, Error>>()
+        .map_err(ctx)?;
+    let (r, m) = crt(&pairs).map_err(ctx)?;
+    Ok((Decimal::from(r), Decimal::from(m)))
+}
+
+/// (a/p) on integral Decimals
+pub fn d_legendre(
+    value: Decimal,
+    modulus: Decimal
+) -> Result<Decimal, Error> {
+    let ctx = |e: Error| e.context("d_legendre", format!("{}, {}", value, modulus));
+    let a = d_to_u64(value).map_err(ctx)?;
+    let p = d_to_u64(modulus).map_err(ctx)?;
+    Ok(Decimal::from(legendre(a, p).map_err(ctx)?))
+}
+
+/// (a/n) on integral Decimals
+pub fn d_jacobi(
+    value: Decimal,
+    modulus: Decimal
+) -> Result<Decimal, Error> {
+    let ctx = |e: Error| e.context("d_jacobi", format!("{}, {}", value, modulus));
+    let a = d_to_u64(value).map_err(ctx)?;
+    let n = d_to_u64(modulus).map_err(ctx)?;
+    Ok(Decimal::from(jacobi(a, n).map_err(ctx)?))
+}
+
+/// sqrt(a) mod p on integral Decimals
+pub fn d_sqrt_mod(
+    value: Decimal,
+    modulus: Decimal
+) -> Result<Decimal, Error> {
+    let ctx = |e: Error| e.context("d_sqrt_mod", format!("{}, {}", value, modulus));
+    let a = d_to_u64(value).map_err(ctx)?;
+    let p = d_to_u64(modulus).map_err(ctx)?;
+    Ok(Decimal::from(sqrt_mod(a, p).map_err(ctx)?))
+}
+
+/// Smallest primitive root mod n on integral Decimals
+pub fn d_primitive_root(
+    value: Decimal
+) -> Result<Decimal, Error> {
+    let ctx = |e: Error| e.context("d_primitive_root", value);
+    let n = d_to_u64(value).map_err(ctx)?;
+    Ok(Decimal::from(primitive_root(n).map_err(ctx)?))
+}
+
+//##########################################################################################################################
@@ -0,0 +1,194 @@
+// Imports
+use rust_decimal::prelude::*;
+use rust_decimal_macros::dec;
+
+// Modules
+use crate::error::{ Error, ErrorKind };
+
+use crate::number_theory::arithmetic::{ gcd, lcm, ext_gcd, totient, carmichael, sigma, tau, radical };
+use crate::number_theory::arithmetic::{ d_gcd, d_lcm, d_ext_gcd, d_totient, d_carmichael, d_sigma, d_tau, d_radical };
+use crate::number_theory::modular::{ mod_pow, mod_inv, crt, legendre, jacobi, sqrt_mod, primitive_root };
+use crate::number_theory::modular::{ d_mod_pow, d_mod_inv, d_crt, d_sqrt_mod, d_primitive_root };
+use crate::number_theory::bernoulli::{ bernoulli, d_bernoulli, zeta_even };
+
+//##########################################################################################################################
+
+// Constants
+const D12: Decimal = dec!(12);
+const D18: Decimal = dec!(18);
+const D36: Decimal = dec!(36);
+const D72: Decimal = dec!(72);
+
+// ζ(2) == π^2 / 6
+const ZETA_2: Decimal = dec!(1.6449340668482264364724151666);
+
+// Primes
+const P_1E9_7: u64 = 1_000_000_007;
+const P_NTT: u64 = 998_244_353;
+const P_U64_MAX: u64 = 18_446_744_073_709_551_557;
+
+//##########################################################################################################################
+
+#[test]
+fn arithmetic() -> Result<(), Error> {
+    // gcd(240, 46) == 2
+    assert_eq!(gcd(240, 46), 2);
+    // gcd(0, 0) == 0
+    assert_eq!(gcd(0, 0), 0);
+    // lcm(4, 6) == 12
+    assert_eq!(lcm(4, 6)?, 12);
+    // 240 * -9 + 46 * 47 == 2
+    assert_eq!(ext_gcd(240, 46), (2, -9, 47));
+    // φ(36) == 12, φ(97) == 96, φ(1) == 1
+    assert_eq!(totient(36)?, 12);
+    assert_eq!(totient(97)?, 96);
+    assert_eq!(totient(1)?, 1);
+    // λ(8) == 2, λ(16) == 4, λ(561) == 80, λ(1000) == 100
+    assert_eq!(carmichael(8)?, 2);
+    assert_eq!(carmichael(16)?, 4);
+    assert_eq!(carmichael(561)?, 80);
+    assert_eq!(carmichael(1_000)?, 100);
+    // σ_1(12) == 28, σ_2(12) == 210, σ_1(10^6) == 2480437
+    assert_eq!(sigma(12, 1)?, 28);
+    assert_eq!(sigma(12, 2)?, 210);
+    assert_eq!(sigma(1_000_000, 1)?, 2_480_437);
+    // τ(12) == 6 == σ_0(12)
+    assert_eq!(tau(12)?, 6);
+    assert_eq!(sigma(12, 0)?, 6);
+    // rad(72) == 6
+    assert_eq!(radical(72)?, 6);
+    // lcm(2^63, 3) == MultiplyOverflow
+    let res1 = lcm(1 << 63, 3).map_err(|e| e.kind());
+    assert_eq!(res1, Err(ErrorKind::MultiplyOverflow));
+    // φ(0) == InputOutOfRange
+    let res2 = totient(0).map_err(|e| e.kind());
+    assert_eq!(res2, Err(ErrorKind::InputOutOfRange));
+    // Return Ok
+    Ok(())
+}
+
+//##########################################################################################################################
+
+#[test]
+fn decimal() -> Result<(), Error> {
+    // gcd(-12, 18) == 6
+    assert_eq!(d_gcd(-D12, D18)?, dec!(6));
+    // lcm(12, 18) == 36
+    assert_eq!(d_lcm(D12, D18)?, D36);
+    // 12 * -1 + 18 * 1 == 6
+    assert_eq!(d_ext_gcd(D12, D18)?, (dec!(6), dec!(-1), dec!(1)));
+    // -12 * 1 + 18 * 1 == 6, 12 * -1 + -18 * -1 == 6
+    assert_eq!(d_ext_gcd(-D12, D18)?, (dec!(6), dec!(1), dec!(1)));
+    assert_eq!(d_ext_gcd(D12, -D18)?, (dec!(6), dec!(-1), dec!(-1)));
+    // φ(36) == 12, λ(36) == 6
+    assert_eq!(d_totient(D36)?, D12);
+    assert_eq!(d_carmichael(D36)?, dec!(6));
+    // σ_1(12) == 28, τ(12) == 6, rad(72) == 6
+    assert_eq!(d_sigma(D12, 1)?, dec!(28));
+    assert_eq!(d_tau(D12)?, dec!(6));
+    assert_eq!(d_radical(D72)?, dec!(6));
+    // φ(1.5) == InputOutOfRange
+    let res1 = d_totient(dec!(1.5)).unwrap_err();
+    assert_eq!(res1.kind(), ErrorKind::InputOutOfRange);
+    assert_eq!(res1.func(), Some("d_totient"));
+    // φ(10^20) == InputOutOfRange
+    let res2 = d_totient(dec!(100000000000000000000)).map_err(|e| e.kind());
+    assert_eq!(res2, Err(ErrorKind::InputOutOfRange));
+    // Return Ok
+    Ok(())
+}
+
+//##########################################################################################################################
+
+#[test]
+fn modular() -> Result<(), Error> {
+    // 3^200 mod (10^9 + 7) == 136318165
+    assert_eq!(mod_pow(3, 200, P_1E9_7)?, 136_318_165);
+    // 3^-1 mod 11 == 4, 10^-1 mod 17 == 12
+    assert_eq!(mod_inv(3, 11)?, 4);
+    assert_eq!(mod_inv(10, 17)?, 12);
+    // x ≡ 2 (3), x ≡ 3 (5), x ≡ 2 (7) => x ≡ 23 (105)
+    assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)])?, (23, 105));
+    // x ≡ 3 (4), x ≡ 5 (6) => x ≡ 11 (12)
+    assert_eq!(crt(&[(3, 4), (5, 6)])?, (11, 12));
+    // (2/7) == 1, (3/7) == -1
+    assert_eq!(legendre(2, 7)?, 1);
+    assert_eq!(legendre(3, 7)?, -1);
+    // (1001/9907) == -1, (19/45) == 1
+    assert_eq!(jacobi(1_001, 9_907)?, -1);
+    assert_eq!(jacobi(19, 45)?, 1);
+    // sqrt(10) mod 13 == 6, sqrt(5) mod 41 == 13
+    assert_eq!(sqrt_mod(10, 13)?, 6);
+    assert_eq!(sqrt_mod(5, 41)?, 13);
+    // p - 1 = 119 * 2^23
+    assert_eq!(sqrt_mod(2, P_NTT)?, 116_195_171);
+    // largest 64-bit prime
+    assert_eq!(sqrt_mod(6, P_U64_MAX)?, 3_789_919_121_787_743_779);
+    // primitive roots
+    assert_eq!(primitive_root(7)?, 3);
+    assert_eq!(primitive_root(25)?, 2);
+    assert_eq!(primitive_root(54)?, 5);
+    assert_eq!(primitive_root(P_1E9_7)?, 5);
+    // 2^-1 mod 4 == DomainError
+    let res1 = mod_inv(2, 4).map_err(|e| e.kind());
+    assert_eq!(res1, Err(ErrorKind::DomainError));
+    // x ≡ 1 (2), x ≡ 0 (4) == DomainError
+    let res2 = crt(&[(1, 2), (0, 4)]).map_err(|e| e.kind());
+    assert_eq!(res2, Err(ErrorKind::DomainError));
+    // sqrt(3) mod 7 == DomainError
+    let res3 = sqrt_mod(3, 7).map_err(|e| e.kind());
+    assert_eq!(res3, Err(ErrorKind::DomainError));
+    // primitive root mod 8 == DomainError
+    let res4 = primitive_root(8).map_err(|e| e.kind());
+    assert_eq!(res4, Err(ErrorKind::DomainError));
+    // x mod 0 == DivisionByZero
+    let res5 = mod_pow(2, 3, 0).map_err(|e| e.kind());
+    assert_eq!(res5, Err(ErrorKind::DivisionByZero));
+    // Decimal variants
+    assert_eq!(d_mod_pow(dec!(3), dec!(200), Decimal::from(P_1E9_7))?, dec!(136318165));
+    assert_eq!(d_mod_inv(dec!(3), dec!(11))?, dec!(4));
+    assert_eq!(d_crt(&[(dec!(3), dec!(4)), (dec!(5), dec!(6))])?, (dec!(11), D12));
+    assert_eq!(d_sqrt_mod(dec!(10), dec!(13))?, dec!(6));
+    assert_eq!(d_primitive_root(dec!(23))?, dec!(5));
+    // Return Ok
+    Ok(())
+}
+
+//##########################################################################################################################
+
+//##########################################################################################################################
+
+#[test]
+fn bernoulli_numbers() -> Result<(), Error> {
+    // B_0 == 1, B_1 == -1/2, B_3 == 0
+    assert_eq!(bernoulli(0)?, (1, 1));
+    assert_eq!(bernoulli(1)?, (-1, 2));
+    assert_eq!(bernoulli(3)?, (0, 1));
+    // B_12 == -691/2730
+    assert_eq!(bernoulli(12)?, (-691, 2730));
+    // B_58 == 84483613348880041862046775994036021/354
+    assert_eq!(bernoulli(58)?, (84_483_613_348_880_041_862_046_775_994_036_021, 354));
+    // B_30 == 8615841276005/14322
+    assert_eq!(d_bernoulli(30)?, dec!(601580873.90064236838430386817));
+    // B_54 rounds to an integer at Decimal precision
+    assert_eq!(d_bernoulli(54)?, dec!(36528776484818123335110430843));
+    // ζ(0) == -1/2, ζ(2) == π^2 / 6
+    assert_eq!(zeta_even(0)?, dec!(-0.5));
+    assert_eq!(zeta_even(2)?.round_dp(25), ZETA_2.round_dp(25));
+    // ζ(26) == 1.0000000149015548283650412347
+    assert_eq!(zeta_even(26)?.round_dp(25), dec!(1.0000000149015548283650412));
+    // ζ(58), ζ(62) on either side of the exact Bernoulli numbers
+    assert_eq!(zeta_even(58)?.round_dp(25), dec!(1.0000000000000000034694470));
+    assert_eq!(zeta_even(62)?.round_dp(25), dec!(1.0000000000000000002168404));
+    // B_59 == InputOutOfRange
+    let res1 = bernoulli(59).map_err(|e| e.kind());
+    assert_eq!(res1, Err(ErrorKind::InputOutOfRange));
+    // B_56 > Decimal::MAX == MultiplyOverflow
+    let res2 = d_bernoulli(56).map_err(|e| e.kind());
+    assert_eq!(res2, Err(ErrorKind::MultiplyOverflow));
+    // ζ(3) == InputOutOfRange
+    let res3 = zeta_even(3).map_err(|e| e.kind());
+    assert_eq!(res3, Err(ErrorKind::InputOutOfRange));
+    // Return Ok
+    Ok(())
+}
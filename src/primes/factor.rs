@@ -0,0 +1,158 @@
+
+// Modules
+use crate::error::{ Error, ErrorKind };
+use crate::number_theory::arithmetic::{ gcd };
+
+//##########################################################################################################################
+
+// Constants
+// Witnesses making Miller-Rabin deterministic for n < 3.3 * 10^24
+const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+// Trial division bound before Pollard's rho
+const TRIAL_UPPER_BD: u64 = 1_000;
+
+// Products accumulated between gcd steps in Brent's cycle
+const BRENT_BATCH: u64 = 128;
+
+//##########################################################################################################################
+
+/// (a * b) mod m
+#[inline]
+pub(crate) fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    (((a as u128) * (b as u128)) % (m as u128)) as u64
+}
+
+/// (a + b) mod m
+#[inline]
+fn add_mod(a: u64, b: u64, m: u64) -> u64 {
+    (((a as u128) + (b as u128)) % (m as u128)) as u64
+}
+
+/// a^e mod m by binary exponentiation
+#[inline]
+pub(crate) fn pow_mod(a: u64, e: u64, m: u64) -> u64 {
+    let mut base = a % m;
+    let mut exp = e;
+    let mut result = 1 % m;
+    while exp > 0 {
+        if (exp & 1) == 1 { result = mul_mod(result, base, m); };
+        base = mul_mod(base, base, m);
+        exp >>= 1;
+    };
+    result
+}
+
+//##########################################################################################################################
+
+/// Deterministic Miller-Rabin primality test on 64-bit integers
+pub fn is_prime(
+    value: u64
+) -> bool {
+    if value < 2 { return false };
+    for p in WITNESSES {
+        if let 0=value%p { return value == p };
+    };
+    // n - 1 = d * 2^s
+    let s = (value - 1).trailing_zeros();
+    let d = (value - 1) >> s;
+    'witness: for a in WITNESSES {
+        let mut x = pow_mod(a, d, value);
+        if (x == 1) || (x == (value - 1)) { continue };
+        for _ in 1..s {
+            x = mul_mod(x, x, value);
+            if x == (value - 1) { continue 'witness };
+        };
+        return false;
+    };
+    true
+}
+
+//##########################################################################################################################
+
+/// Non-trivial factor of an odd composite n by Pollard's rho with Brent's cycle detection
+fn pollard_brent(
+    value: u64
+) -> u64 {
+    let mut c: u64 = 1;
+    loop {
+        let f = |x: u64| add_mod(mul_mod(x, x, value), c, value);
+        // Set Variables
+        let (mut x, mut y, mut ys) = (2, 2, 2);
+        let (mut g, mut q, mut r) = (1, 1, 1);
+        // Find cycle in powers of two
+        while g == 1 {
+            x = y;
+            for _ in 0..r { y = f(y); };
+            let mut k = 0;
+            while (k < r) && (g == 1) {
+                ys = y;
+                let batch = if (r - k) < BRENT_BATCH {r - k} else {BRENT_BATCH};
+                for _ in 0..batch {
+                    y = f(y);
+                    q = mul_mod(q, x.abs_diff(y), value);
+                };
+                g = gcd(q, value);
+                k += BRENT_BATCH;
+            };
+            r *= 2;
+        };
+        // Batch overshot, step back one at a time
+        if g == value {
+            loop {
+                ys = f(ys);
+                g = gcd(x.abs_diff(ys), value);
+                if g > 1 {break};
+            };
+        };
+        if g != value { return g };
+        c += 1;
+    }
+}
+
+/// Push prime factors of n into the list
+fn factor_rho(
+    value: u64,
+    factors: &mut Vec<u64>
+) {
+    if value == 1 { return };
+    if is_prime(value) { return factors.push(value) };
+    let d = pollard_brent(value);
+    factor_rho(d, factors);
+    factor_rho(value / d, factors);
+}
+
+//##########################################################################################################################
+
+/// n = p1^e1 * p2^e2 * ... as [(p1, e1), (p2, e2), ...] in increasing order
+pub fn factorize(
+    value: u64
+) -> Result<Vec<(u64, u32)>, Error> {
+    if value == 0 { Err(Error::from(ErrorKind::InputOutOfRange).context("factorize", value))? };
+    // Set Variables
+    let mut acc: u64 = value;
+    let mut factors: Vec<u64> = Vec::new();
+    // Trial division by small factors
+    let mut i: u64 = 2;
+    while (i <= TRIAL_UPPER_BD) && (i <= (acc / i)) {
+        while let 0=acc%i {
+            factors.push(i);
+            acc /= i;
+        };
+        i = i + if i == 2 {1} else {2};
+    };
+    // Pollard's rho on the remaining cofactor
+    factor_rho(acc, &mut factors);
+    factors.sort_unstable();
+    // Group equal primes
+    let mut result: Vec<(u64, u32)> = Vec::new();
+    for p in factors {
+        match result.last_mut() {
+            Some((q, e)) if *q == p => { *e += 1; },
+            _ => result.push((p, 1)),
+        };
+    };
+    Ok(result)
+}
+
+//##########################################################################################################################
@@ -1,2 +1,6 @@
-
 pub mod riemann;
+pub mod sieve;
+pub mod factor;
+
+#[cfg(test)]
+mod tests;
@@ -4,7 +4,14 @@ use rust_decimal_macros::dec;
 use rust_decimal::prelude::*;
 
 // Modules
-use crate::error::Error;
+use crate::error::{ Error, ErrorKind };
+
+use crate::basic::{ Terms, dec };
+use crate::euler::{ d_ln_helper };
+
+use crate::primes::factor::{ factorize };
+use crate::primes::sieve::{ primes_up_to, u_root };
+use crate::special::expint::{ ei_helper };
 
 //##########################################################################################################################
 
@@ -13,37 +20,245 @@ const D0: Decimal = dec!(0);
 const D1: Decimal = dec!(1);
 const D1NEG: Decimal = dec!(-1);
 
+// Largest sieve length, keeping the tables of μ(n) within memory
+const SIEVE_UPPER_BD: usize = 10_000_000;
+
+// Largest number of terms in Riemann's R function, which converges within about 100
+const R_TERMS_UPPER_BD: usize = 1_000;
+
+// Largest value handled by the prime counting function
+const PI_UPPER_BD: usize = 1 << 48;
+
+// Bounds of the table of primes used by the prime counting function
+const PI_TABLE_LOWER_BD: usize = 1 << 10;
+const PI_TABLE_UPPER_BD: usize = 1 << 27;
+
+// Primorial 2 * 3 * 5 * 7 * 11 * 13 and its totient, the period of φ(x, 6)
+const WHEEL_PRIMES: usize = 6;
+const WHEEL_LEN: usize = 30_030;
+const WHEEL_PHI: usize = 5_760;
+
+//##########################################################################################################################
+
+/// Count prime factors of n as (distinct, with multiplicity)
+#[inline]
+fn prime_factor_count(
+    value: usize
+) -> Result<(usize, usize), Error> {
+    let factors = factorize(value as u64)?;
+    let total = factors.iter().map(|&(_, e)| e as usize).sum();
+    Ok((factors.len(), total))
+}
+
 //##########################################################################################################################
 
-fn mobius(
+/// μ(n) = 0 if n has a squared prime factor, else (-1)^k for k distinct primes
+pub fn mobius(
     value: usize
 ) -> Result<Decimal, Error> {
-    if value <= 0 { Err(Error::InputOutOfRange)? };
+    let (distinct, total) = prime_factor_count(value).map_err(|e| e.context("mobius", value))?;
+    Ok(
+             if distinct != total { D0 }
+        else if let 0=distinct%2  { D1 }
+        else                      { D1NEG }
+    )
+}
+
+//##########################################################################################################################
+
+/// λ(n) = (-1)^Ω(n) with Ω(n) the number of prime factors counted with multiplicity
+pub fn liouville(
+    value: usize
+) -> Result<Decimal, Error> {
+    let (_, total) = prime_factor_count(value).map_err(|e| e.context("liouville", value))?;
+    Ok(if let 0=total%2 {D1} else {D1NEG})
+}
+
+//##########################################################################################################################
+
+/// Linear sieve of μ(n) for n in 0..=N
+#[inline]
+fn mobius_table(
+    value: usize
+) -> Vec<i8> {
     // Set Variables
-    let mut acc: usize = value;
-    let mut primes: usize = 0;
-    // Check 2
-    if let 0=acc%2 {
-        primes = primes + 1;
-        acc = acc / 2;
-        if let 0=acc%2 {return Ok(D0)};
-    };
-    // Check All Primes
-    let i: usize = 3;
-    loop {
-        if (i * i) > n {break};
-        if let 0=acc%i {
-            primes = primes + 1;
-            acc = acc / i;
-            if let 0=acc%i {return Ok(D0)};
+    let mut mu: Vec<i8> = vec![1; value + 1];
+    let mut composite: Vec<bool> = vec![false; value + 1];
+    let mut primes: Vec<usize> = Vec::new();
+    mu[0] = 0;
+    // Sieve over Integers
+    for i in 2..=value {
+        if !composite[i] {
+            primes.push(i);
+            mu[i] = -1;
+        };
+        for &p in primes.iter() {
+            if i > (value / p) {break};
+            composite[i * p] = true;
+            if let 0=i%p {
+                mu[i * p] = 0;
+                break;
+            };
+            mu[i * p] = -mu[i];
         };
-        i = i + 2;
     };
-    // Return Even or Odd
+    mu
+}
+
+/// μ(n) for every n in 0..=N, with μ(0) = 0
+pub fn mobius_sieve(
+    value: usize
+) -> Result<Vec<Decimal>, Error> {
+    if value > SIEVE_UPPER_BD { Err(Error::from(ErrorKind::InputOutOfRange).context("mobius_sieve", value))? };
     Ok(
-        if let 0=primes%2 {D1}
-        else {D1NEG}
+        mobius_table(value).into_iter()
+            .map(Decimal::from)
+            .collect()
     )
 }
 
 //##########################################################################################################################
+
+/// M(x) = sum(n=1; x; μ(n))
+pub fn mertens(
+    value: usize
+) -> Result<Decimal, Error> {
+    if value > SIEVE_UPPER_BD { Err(Error::from(ErrorKind::InputOutOfRange).context("mertens", value))? };
+    let sum: i64 = mobius_table(value).into_iter()
+        .map(i64::from)
+        .sum();
+    Ok(Decimal::from(sum))
+}
+
+//##########################################################################################################################
+
+/// Sieved primes up to a limit, with the wheel of φ(x, 6)
+struct PiTable {
+    /// Primes p <= limit
+    primes: Vec<u64>,
+    /// Odd primes as bits, n = 2 * (64 * word + bit) + 1
+    bits: Vec<u64>,
+    /// π(x) at the start of each word of bits
+    counts: Vec<usize>,
+    /// φ(x, 6) over one period
+    wheel: Vec<usize>,
+    limit: usize
+}
+
+impl PiTable {
+    /// Sieve primes up to the limit
+    #[inline]
+    fn new(limit: usize) -> Result<Self, Error> {
+        let primes = primes_up_to(limit as u64)?;
+        let mut bits: Vec<u64> = vec![0; (limit / 128) + 1];
+        for &p in primes.iter().skip(1) {
+            let i = (p / 2) as usize;
+            bits[i / 64] |= 1 << (i % 64);
+        };
+        // Prime 2 is counted ahead of the first word
+        let mut acc: usize = 1;
+        let counts = bits.iter()
+            .map(|w| {
+                let start = acc;
+                acc += w.count_ones() as usize;
+                start
+            })
+            .collect();
+        Ok(Self { primes, bits, counts, wheel: phi_wheel(), limit })
+    }
+}
+
+/// π(x) from the bit table, for x below the sieve limit
+#[inline]
+fn pi_table(
+    value: usize,
+    table: &PiTable
+) -> usize {
+    if value < 2 { return 0 };
+    // Odd numbers n <= x have index n/2 <= (x - 1)/2
+    let i = (value - 1) / 2;
+    let mask = if (i % 64) == 63 {u64::MAX} else {(1 << ((i % 64) + 1)) - 1};
+    table.counts[i / 64] + ((table.bits[i / 64] & mask).count_ones() as usize)
+}
+
+/// φ(x, 6) for x in 0..30030, as a running count of integers coprime to 30030
+#[inline]
+fn phi_wheel() -> Vec<usize> {
+    let mut count: usize = 0;
+    (0..WHEEL_LEN)
+        .map(|n| {
+            if [2, 3, 5, 7, 11, 13].iter().all(|&p| n % p != 0) { count += 1; };
+            count
+        })
+        .collect()
+}
+
+/// φ(x, a) = count of n <= x not divisible by any of the first a primes
+fn phi(
+    value: usize,
+    count: usize,
+    table: &PiTable
+) -> usize {
+    if count == 0 { return value };
+    let p = table.primes[count - 1] as usize;
+    // Only 1 survives below p_a
+    if value < p { return if value > 0 {1} else {0} };
+    // φ(x, 6) is periodic
+    if count == WHEEL_PRIMES { return ((value / WHEEL_LEN) * WHEEL_PHI) + table.wheel[value % WHEEL_LEN] };
+    // Survivors are 1 and the primes in (p_a, x] when x < p_(a+1)^2
+    if let Some(&q) = table.primes.get(count) {
+        let q = q as usize;
+        if (value <= table.limit) && (value < (q * q)) { return pi_table(value, table) + 1 - count };
+    };
+    phi(value, count - 1, table) - phi(value / p, count - 1, table)
+}
+
+/// π(x) by Meissel's formula, with a = π(x^(1/3)) and b = π(x^(1/2))
+/// π(x) = φ(x, a) + a - 1 - sum(i=a+1; b; π(x/p_i) - (i - 1))
+fn pi_meissel(
+    value: usize,
+    table: &PiTable
+) -> usize {
+    if value <= table.limit { return pi_table(value, table) };
+    let a = pi_meissel(u_root(value as u64, 3) as usize, table);
+    let b = pi_meissel(u_root(value as u64, 2) as usize, table);
+    let sum: usize = ((a + 1)..=b)
+        .map(|i| pi_meissel(value / (table.primes[i - 1] as usize), table) - (i - 1))
+        .sum();
+    phi(value, a, table) + a - 1 - sum
+}
+
+/// π(x) = number of primes p <= x, exact
+pub fn prime_pi_exact(
+    value: usize
+) -> Result<Decimal, Error> {
+    let ctx = |e: Error| e.context("prime_pi_exact", value);
+    if value > PI_UPPER_BD { Err(ctx(ErrorKind::InputOutOfRange.into()))? };
+    // Sieve up to x^(2/3) within the table bounds, and never below sqrt(x)
+    let cube = u_root(value as u64, 3) as usize;
+    let limit = (cube * cube).clamp(PI_TABLE_LOWER_BD, PI_TABLE_UPPER_BD);
+    let table = PiTable::new(limit).map_err(ctx)?;
+    Ok(Decimal::from(pi_meissel(value, &table)))
+}
+
+//##########################################################################################################################
+
+/// R(x) = sum(n=1; μ(n)/n * li(x^(1/n))), truncated after `terms` values of n
+pub fn riemann_r(
+    value: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    let ctx = |e: Error| e.context("riemann_r", format!("{}, {}", value, terms));
+    if (value <= D1) || (terms == 0) || (terms > R_TERMS_UPPER_BD) { Err(ctx(ErrorKind::InputOutOfRange.into()))? };
+    let (ln, _) = d_ln_helper(value, Terms::Digits(28)).map_err(ctx)?;
+    // li(x^(1/n)) = Ei(ln(x)/n)
+    mobius_table(terms).into_iter().enumerate()
+        .filter(|&(_, mu)| mu != 0)
+        .try_fold(D0, |acc, (n, mu)| {
+            let li = ei_helper(ln / dec(n), Terms::Digits(28))?;
+            acc.checked_add(Decimal::from(mu) * (li / dec(n))).ok_or(ErrorKind::AddOverflow.into())
+        })
+        .map_err(ctx)
+}
+
+//##########################################################################################################################
@@ -0,0 +1,256 @@
+
+// Imports
+use rayon::prelude::*;
+
+// Modules
+use crate::error::{ Error, ErrorKind };
+
+//##########################################################################################################################
+
+// Constants
+const SEGMENT_LEN: u64 = 1 << 16;
+const SEGMENT_BATCH: u64 = 16;
+
+// Largest value handled by the sieve
+const SIEVE_UPPER_BD: u64 = 1 << 48;
+
+// Largest value whose primes are collected into a Vec, about 15 million primes
+const COLLECT_UPPER_BD: u64 = 1 << 28;
+
+//##########################################################################################################################
+
+/// floor(n^(1/k)) on integers, for k >= 2
+#[inline]
+pub(crate) fn u_root(
+    value: u64,
+    power: u32
+) -> u64 {
+    let mut root = (value as f64).powf(1.0 / (power as f64)) as u64;
+    let above = |r: u64| r.checked_pow(power).is_none_or(|r| r > value);
+    while (root > 0) && above(root) { root -= 1; };
+    while !above(root + 1) { root += 1; };
+    root
+}
+
+//##########################################################################################################################
+
+/// Primes p <= n by the Sieve of Eratosthenes
+#[inline]
+fn simple_sieve(
+    value: u64
+) -> Vec<u64> {
+    let len = (value as usize) + 1;
+    let mut composite: Vec<bool> = vec![false; len];
+    let mut primes: Vec<u64> = Vec::new();
+    for i in 2..len {
+        if composite[i] {continue};
+        primes.push(i as u64);
+        let mut j = i * i;
+        while j < len {
+            composite[j] = true;
+            j += i;
+        };
+    };
+    primes
+}
+
+//##########################################################################################################################
+
+/// Primes in [low, high) crossed out by the base primes
+#[inline]
+fn sieve_segment(
+    low: u64,
+    high: u64,
+    base: &[u64]
+) -> Vec<u64> {
+    let low = if low < 2 {2} else {low};
+    if low >= high { return Vec::new() };
+    let mut composite: Vec<bool> = vec![false; (high - low) as usize];
+    for &p in base {
+        if (p * p) >= high {break};
+        // First multiple of p in the segment, not below p^2
+        let first = low.div_ceil(p) * p;
+        let mut j = if first < (p * p) {p * p} else {first};
+        while j < high {
+            composite[(j - low) as usize] = true;
+            j += p;
+        };
+    };
+    composite.iter().enumerate()
+        .filter(|(_, &c)| !c)
+        .map(|(i, _)| low + (i as u64))
+        .collect()
+}
+
+/// Primes in [low, high), one segment per rayon task
+#[inline]
+fn sieve_range(
+    low: u64,
+    high: u64,
+    base: &[u64]
+) -> Vec<u64> {
+    if low >= high { return Vec::new() };
+    let segments = (high - low).div_ceil(SEGMENT_LEN);
+    (0..segments).into_par_iter()
+        .map(|k| {
+            let start = low + (k * SEGMENT_LEN);
+            let end = if (high - start) > SEGMENT_LEN {start + SEGMENT_LEN} else {high};
+            sieve_segment(start, end, base)
+        })
+        .collect::<Vec<Vec<u64>>>()
+        .concat()
+}
+
+//##########################################################################################################################
+
+/// Lazy iterator over the primes in increasing order.
+#[derive(Clone, Debug)]
+pub struct Primes {
+    /// Primes used to cross out composites
+    base: Vec<u64>,
+    /// Largest value covered by the base primes
+    base_limit: u64,
+    /// Primes found but not yet returned
+    buffer: std::vec::IntoIter<u64>,
+    /// Start of the next range to sieve
+    low: u64
+}
+
+impl Default for Primes {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Primes {
+    /// Create iterator starting at 2
+    #[inline]
+    pub fn new() -> Self {
+        Self::starting_at(2)
+    }
+
+    /// Create iterator over primes p >= value
+    #[inline]
+    pub fn starting_at(value: u64) -> Self {
+        Self { base: Vec::new(), base_limit: 0, buffer: Vec::new().into_iter(), low: value }
+    }
+
+    /// Sieve the next batch of segments
+    #[inline]
+    fn refill(&mut self) {
+        let span = SEGMENT_LEN * SEGMENT_BATCH;
+        let high = if (SIEVE_UPPER_BD - self.low) > span {self.low + span} else {SIEVE_UPPER_BD};
+        // Extend base primes up to sqrt(high)
+        let needed = u_root(high - 1, 2);
+        if needed > self.base_limit {
+            self.base_limit = if needed > (2 * self.base_limit) {needed} else {2 * self.base_limit};
+            self.base = simple_sieve(self.base_limit);
+        };
+        self.buffer = sieve_range(self.low, high, &self.base).into_iter();
+        self.low = high;
+    }
+}
+
+impl Iterator for Primes {
+    type Item = u64;
+
+    #[inline]
+    fn next(&mut self) -> Option<u64> {
+        loop {
+            if let Some(p) = self.buffer.next() { return Some(p) };
+            if self.low >= SIEVE_UPPER_BD { return None };
+            self.refill();
+        }
+    }
+}
+
+/// Lazy iterator over all primes. 2, 3, 5, 7, 11, ...
+#[inline]
+pub fn primes() -> Primes {
+    Primes::new()
+}
+
+//##########################################################################################################################
+
+/// All primes p <= n
+pub fn primes_up_to(
+    value: u64
+) -> Result<Vec<u64>, Error> {
+    if value >= COLLECT_UPPER_BD { Err(Error::from(ErrorKind::InputOutOfRange).context("primes_up_to", value))? };
+    let base = simple_sieve(u_root(value, 2));
+    Ok(sieve_range(2, value + 1, &base))
+}
+
+//##########################################################################################################################
+
+/// p(n) < n * (ln(n) + ln(ln(n))) for n >= 6
+#[inline]
+fn nth_prime_upper_bd(
+    value: u64
+) -> u64 {
+    if value < 6 { return 15 };
+    let n = value as f64;
+    (n * (n.ln() + n.ln().ln())).ceil() as u64
+}
+
+/// n-th prime, with p(1) = 2
+pub fn nth_prime(
+    value: u64
+) -> Result<u64, Error> {
+    let err = |kind: ErrorKind| Error::from(kind).context("nth_prime", value);
+    if value == 0 { Err(err(ErrorKind::InputOutOfRange))? };
+    let high = nth_prime_upper_bd(value) + 1;
+    if high >= SIEVE_UPPER_BD { Err(err(ErrorKind::InputOutOfRange))? };
+    let base = simple_sieve(u_root(high, 2));
+    // Count primes per segment in parallel
+    let segments = high.div_ceil(SEGMENT_LEN);
+    let counts: Vec<u64> = (0..segments).into_par_iter()
+        .map(|k| {
+            let start = k * SEGMENT_LEN;
+            let end = if (high - start) > SEGMENT_LEN {start + SEGMENT_LEN} else {high};
+            sieve_segment(start, end, &base).len() as u64
+        })
+        .collect();
+    // Find segment holding the n-th prime
+    let mut acc: u64 = 0;
+    for (k, &count) in counts.iter().enumerate() {
+        if (acc + count) >= value {
+            let start = (k as u64) * SEGMENT_LEN;
+            let end = if (high - start) > SEGMENT_LEN {start + SEGMENT_LEN} else {high};
+            let found = sieve_segment(start, end, &base);
+            return Ok(found[(value - acc - 1) as usize]);
+        };
+        acc += count;
+    };
+    Err(err(ErrorKind::NotConverged))
+}
+
+//##########################################################################################################################
+
+/// Smallest prime p > n
+pub fn next_prime(
+    value: u64
+) -> Result<u64, Error> {
+    let err = || Error::from(ErrorKind::InputOutOfRange).context("next_prime", value);
+    if value >= (SIEVE_UPPER_BD - 1) { Err(err())? };
+    Primes::starting_at(value + 1).next().ok_or_else(err)
+}
+
+/// Largest prime p < n
+pub fn prev_prime(
+    value: u64
+) -> Result<u64, Error> {
+    let err = || Error::from(ErrorKind::InputOutOfRange).context("prev_prime", value);
+    if (value <= 2) || (value > SIEVE_UPPER_BD) { Err(err())? };
+    let base = simple_sieve(u_root(value, 2));
+    let mut high = value;
+    loop {
+        let low = if high > SEGMENT_LEN {high - SEGMENT_LEN} else {2};
+        if let Some(&p) = sieve_segment(low, high, &base).last() { return Ok(p) };
+        if low <= 2 { Err(err())? };
+        high = low;
+    }
+}
+
+//##########################################################################################################################
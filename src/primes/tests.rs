@@ -0,0 +1,206 @@
+// Imports
+use rust_decimal::prelude::*;
+use rust_decimal_macros::dec;
+
+// Modules
+use crate::error::{ Error, ErrorKind };
+
+use crate::primes::riemann::{ mobius, liouville, mobius_sieve, mertens, prime_pi_exact, riemann_r };
+use crate::primes::sieve::{ primes, primes_up_to, nth_prime, next_prime, prev_prime };
+use crate::primes::factor::{ is_prime, factorize };
+
+//##########################################################################################################################
+
+// Constants
+const D0: Decimal = Decimal::ZERO;
+const D1: Decimal = Decimal::ONE;
+const D1NEG: Decimal = Decimal::NEGATIVE_ONE;
+
+const D2: Decimal = Decimal::TWO;
+const DN23: Decimal = dec!(-23);
+
+const D100: Decimal = dec!(100);
+const D1E9: Decimal = dec!(1_000_000_000);
+const D1E20: Decimal = dec!(100_000_000_000_000_000_000);
+
+//##########################################################################################################################
+
+// μ(n) for n in 1..=30
+const MOBIUS_30: [i8; 30] = [
+     1, -1, -1,  0, -1,  1, -1,  0,  0,  1,
+    -1,  0, -1,  1,  1,  0, -1,  0, -1,  0,
+     1,  1, -1,  0,  0,  1,  0,  0, -1, -1,
+];
+
+// Primes below 100
+const PRIMES_100: [u64; 25] = [
+     2,  3,  5,  7, 11, 13, 17, 19, 23, 29, 31, 37, 41,
+    43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+];
+
+// λ(n) for n in 1..=10
+const LIOUVILLE_10: [i8; 10] = [1, -1, -1, 1, -1, 1, -1, -1, 1, 1];
+
+//##########################################################################################################################
+
+#[test]
+fn riemann() -> Result<(), Error> {
+    // Set Variables
+    let _sieve = mobius_sieve(30)?;
+    // μ(n) == μ(n)
+    for (i, &mu) in MOBIUS_30.iter().enumerate() {
+        let res1 = mobius(i + 1)?;
+        assert_eq!(res1, Decimal::from(mu));
+        assert_eq!(_sieve[i + 1], Decimal::from(mu));
+    };
+    // μ(0) == 0
+    assert_eq!(_sieve[0], D0);
+    // λ(n) == λ(n)
+    for (i, &lambda) in LIOUVILLE_10.iter().enumerate() {
+        let res2 = liouville(i + 1)?;
+        assert_eq!(res2, Decimal::from(lambda));
+    };
+    // μ(2 * 3 * 5 * 7 * 11 * 13) == 1
+    let res3 = mobius(30_030)?;
+    assert_eq!(res3, D1);
+    // μ(large prime) == -1
+    let res4 = mobius(1_000_000_007)?;
+    assert_eq!(res4, D1NEG);
+    // M(1000) == 2
+    let res5 = mertens(1_000)?;
+    assert_eq!(res5, D2);
+    // M(10000) == -23
+    let res6 = mertens(10_000)?;
+    assert_eq!(res6, DN23);
+    // μ(0) == InputOutOfRange
+    let res7 = mobius(0);
+    assert_eq!(res7.map_err(|e| e.kind()), Err(ErrorKind::InputOutOfRange));
+    // π(x) == π(x) for small x
+    for x in [0, 1, 2, 3, 10, 100, 1_000] {
+        let res8 = prime_pi_exact(x)?;
+        assert_eq!(res8, Decimal::from(primes_up_to(x as u64)?.len()));
+    };
+    // π(10^10) == 455052511
+    let res9 = prime_pi_exact(10_000_000_000)?;
+    assert_eq!(res9, dec!(455_052_511));
+    // π(2^50) == InputOutOfRange
+    let res10 = prime_pi_exact(1 << 50);
+    assert_eq!(res10.map_err(|e| e.kind()), Err(ErrorKind::InputOutOfRange));
+    // R(100) with 10 terms == 25.67866524325657596180
+    let res11 = riemann_r(D100, 10)?;
+    assert_eq!(res11.round_dp(20), dec!(25.67866524325657596180));
+    // R(10^9) with 60 terms == 50847455.4252172655615844
+    let res12 = riemann_r(D1E9, 60)?;
+    assert_eq!(res12.round_dp(16), dec!(50847455.4252172655615844));
+    // R(10^20) with 132 terms == 2220819602556027015.401204
+    let res13 = riemann_r(D1E20, 132)?;
+    assert_eq!(res13.round_dp(6), dec!(2220819602556027015.401204));
+    // R(1) == InputOutOfRange
+    let res14 = riemann_r(D1, 10);
+    assert_eq!(res14.map_err(|e| e.kind()), Err(ErrorKind::InputOutOfRange));
+    // μ(n) up to 10^9 == InputOutOfRange, M(10^9) == InputOutOfRange
+    let res15 = mobius_sieve(1_000_000_000);
+    assert_eq!(res15.map_err(|e| e.kind()), Err(ErrorKind::InputOutOfRange));
+    let res16 = mertens(1_000_000_000);
+    assert_eq!(res16.map_err(|e| e.kind()), Err(ErrorKind::InputOutOfRange));
+    // R(100) with 10^9 terms == InputOutOfRange
+    let res17 = riemann_r(D100, 1_000_000_000);
+    assert_eq!(res17.map_err(|e| e.kind()), Err(ErrorKind::InputOutOfRange));
+    // Return Ok
+    Ok(())
+}
+
+//##########################################################################################################################
+
+#[test]
+fn sieve() -> Result<(), Error> {
+    // primes <= 100 == primes <= 100
+    let res1 = primes_up_to(100)?;
+    assert_eq!(res1, PRIMES_100.to_vec());
+    // first 25 primes == primes <= 100
+    let res2: Vec<u64> = primes().take(25).collect();
+    assert_eq!(res2, PRIMES_100.to_vec());
+    // pi(10^6) == 78498
+    let res3 = primes_up_to(1_000_000)?.len();
+    assert_eq!(res3, 78_498);
+    // lazy iterator crosses segment boundaries
+    let res4 = primes().nth(78_497);
+    assert_eq!(res4, Some(999_983));
+    // p(1) == 2
+    let res5 = nth_prime(1)?;
+    assert_eq!(res5, 2);
+    // p(10000) == 104729
+    let res6 = nth_prime(10_000)?;
+    assert_eq!(res6, 104_729);
+    // next_prime(104729) == 104743
+    let res7 = next_prime(104_729)?;
+    assert_eq!(res7, 104_743);
+    // prev_prime(10^12) == 999999999989
+    let res8 = prev_prime(1_000_000_000_000)?;
+    assert_eq!(res8, 999_999_999_989);
+    // next_prime(10^12) == 1000000000039
+    let res9 = next_prime(1_000_000_000_000)?;
+    assert_eq!(res9, 1_000_000_000_039);
+    // prev_prime(2) == InputOutOfRange
+    let res10 = prev_prime(2);
+    assert_eq!(res10.map_err(|e| e.kind()), Err(ErrorKind::InputOutOfRange));
+    // p(0) == InputOutOfRange
+    let res11 = nth_prime(0);
+    assert_eq!(res11.map_err(|e| e.kind()), Err(ErrorKind::InputOutOfRange));
+    // next_prime(u64::MAX) == InputOutOfRange
+    let res12 = next_prime(u64::MAX);
+    assert_eq!(res12.map_err(|e| e.kind()), Err(ErrorKind::InputOutOfRange));
+    // primes <= u64::MAX == InputOutOfRange
+    let res13 = primes_up_to(u64::MAX);
+    assert_eq!(res13.map_err(|e| e.kind()), Err(ErrorKind::InputOutOfRange));
+    // primes <= 2^40 == InputOutOfRange, while the lazy sieve reaches past it
+    let res14 = primes_up_to(1 << 40);
+    assert_eq!(res14.map_err(|e| e.kind()), Err(ErrorKind::InputOutOfRange));
+    let res15 = next_prime(1 << 40)?;
+    assert_eq!(res15, 1_099_511_627_791);
+    // Return Ok
+    Ok(())
+}
+
+//##########################################################################################################################
+
+#[test]
+fn factor() -> Result<(), Error> {
+    // primes below 100 are prime, everything else is not
+    for n in 0..100 {
+        assert_eq!(is_prime(n), PRIMES_100.contains(&n));
+    };
+    // Carmichael number 561 == composite
+    assert!(!is_prime(561));
+    // strong pseudoprime to bases 2, 3, 5, 7 == composite
+    assert!(!is_prime(3_215_031_751));
+    // largest 64-bit prime == prime
+    assert!(is_prime(18_446_744_073_709_551_557));
+    // 2^64 - 1 == composite
+    assert!(!is_prime(u64::MAX));
+    // 2^64 - 1 == 3 * 5 * 17 * 257 * 641 * 65537 * 6700417
+    let res1 = factorize(u64::MAX)?;
+    assert_eq!(res1, vec![(3, 1), (5, 1), (17, 1), (257, 1), (641, 1), (65_537, 1), (6_700_417, 1)]);
+    // 600851475143 == 71 * 839 * 1471 * 6857
+    let res2 = factorize(600_851_475_143)?;
+    assert_eq!(res2, vec![(71, 1), (839, 1), (1_471, 1), (6_857, 1)]);
+    // product of two 32-bit primes
+    let res3 = factorize(18_446_743_979_220_271_189)?;
+    assert_eq!(res3, vec![(4_294_967_279, 1), (4_294_967_291, 1)]);
+    // 2^10 * 3^5 == 2^10 * 3^5
+    let res4 = factorize(248_832)?;
+    assert_eq!(res4, vec![(2, 10), (3, 5)]);
+    // 1 == empty product
+    let res5 = factorize(1)?;
+    assert_eq!(res5, vec![]);
+    // factorize(0) == InputOutOfRange
+    let res6 = factorize(0);
+    assert_eq!(res6.map_err(|e| e.kind()), Err(ErrorKind::InputOutOfRange));
+    // μ(0) keeps the context of mobius
+    let res7 = mobius(0).unwrap_err();
+    assert_eq!(res7.func(), Some("mobius"));
+    // Return Ok
+    Ok(())
+}
+
+//##########################################################################################################################
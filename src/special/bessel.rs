@@ -0,0 +1,596 @@
+
+// Imports
+use rust_decimal::prelude::*;
+use rust_decimal_macros::dec;
+
+// Modules
+use crate::constants::{ PI, PIDIV2, PIDIV4, EULER_GAMMA };
+use crate::error::{ Error, ErrorKind };
+
+use crate::basic::{ dec };
+use crate::factorial::{ m_fac };
+use crate::sqrt::{ d_sqrt };
+use crate::trigonometry::{ d_sin, d_cos };
+use crate::euler::{ d_exp, d_ln };
+use crate::special::gamma::{ d_lgamma, d_rgamma, sin_pi };
+
+use crate::multiplex::types::{ Multiplex };
+use crate::multiplex::basic::{ ma_pow };
+
+//##########################################################################################################################
+
+// Constants
+const D0: Decimal = Decimal::ZERO;
+const D1: Decimal = Decimal::ONE;
+const D2: Decimal = Decimal::TWO;
+const D4: Decimal = dec!(4);
+const D8: Decimal = dec!(8);
+const D10: Decimal = Decimal::TEN;
+const D1DIV2: Decimal = dec!(0.5);
+const D1DIV4: Decimal = dec!(0.25);
+
+// Largest |ν|, keeping n! of the leading term within m_fac
+const ORDER_UPPER_BD: Decimal = dec!(128);
+
+// Below this bound J_ν(x) uses the power series, and above it Miller's backward recurrence
+const SERIES_UPPER_BD: Decimal = dec!(4);
+
+// Below this bound K_ν(x) uses the power series, and above it Steed's continued fraction
+const K_SERIES_UPPER_BD: Decimal = dec!(2);
+
+// Above this bound K_ν(x) falls below the precision of a Decimal for every order
+const K_UPPER_BD: Decimal = dec!(1000);
+
+// Above this bound J_ν(x) and Y_ν(x) are tried with Hankel's expansion
+const HANKEL_LOWER_BD: Decimal = dec!(35);
+
+// Largest number of terms in the series and in Hankel's expansion
+const SERIES_ITER_UPPER_BD: usize = 255;
+
+// Largest number of steps in the continued fraction and the recurrence
+const FRACTION_ITER_UPPER_BD: usize = 10_000;
+const MILLER_ITER_UPPER_BD: usize = 100_000;
+
+// Orders past max(x, n) where Miller's recurrence starts, over two
+const MILLER_EXTRA: usize = 20;
+
+// Bound and factor of the rescaling in the recurrences, with ln(1 / factor)
+const RESCALE_UPPER_BD: Decimal = dec!(100_000_000_000_000_000_000);
+const RESCALE: Decimal = dec!(0.00000000000000000001);
+const LN_RESCALE: Decimal = dec!(46.051701859880913680359829094);
+
+// Relative precision where the series and the continued fraction stop
+const TOLERANCE: Decimal = dec!(0.000000000000000000000000001);
+
+// c_k of 1/Γ(1 + x) = sum(k=0; c_k * x^k) for k in 0..30, within the precision of a Decimal for |x| <= 1/2
+const RGAMMA_TAYLOR: [Decimal; 30] = [
+    dec!(1),
+    dec!(0.5772156649015328606065120901),
+    dec!(-0.6558780715202538810770195151),
+    dec!(-0.0420026350340952355290039349),
+    dec!(0.1665386113822914895017007951),
+    dec!(-0.0421977345555443367482083013),
+    dec!(-0.0096219715278769735621149217),
+    dec!(0.0072189432466630995423950103),
+    dec!(-0.0011651675918590651121139711),
+    dec!(-0.00021524167411495097281573),
+    dec!(0.0001280502823881161861531986),
+    dec!(-0.0000201348547807882386556894),
+    dec!(-0.0000012504934821426706573454),
+    dec!(0.0000011330272319816958823741),
+    dec!(-0.000000205633841697760710345),
+    dec!(0.0000000061160951044814158179),
+    dec!(0.0000000050020076444692229301),
+    dec!(-0.0000000011812745704870201446),
+    dec!(0.000000000104342671169110051),
+    dec!(0.0000000000077822634399050713),
+    dec!(-0.0000000000036968056186422057),
+    dec!(0.0000000000005100370287454476),
+    dec!(-0.0000000000000205832605356651),
+    dec!(-0.000000000000005348122539423),
+    dec!(0.0000000000000012267786282383),
+    dec!(-0.0000000000000001181259301697),
+    dec!(0.0000000000000000011866922548),
+    dec!(0.0000000000000000014123806553),
+    dec!(-0.0000000000000000002298745684),
+    dec!(0.0000000000000000000171440632),
+];
+
+//##########################################################################################################################
+
+/// cos(πx) = sin(π(x + 1/2))
+#[inline]
+fn cos_pi(
+    value: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    sin_pi(value + D1DIV2, terms)
+}
+
+/// v * e^(-x) = e^(ln(v) - x), with underflow taken as zero
+#[inline]
+fn exp_shift(
+    value: Decimal,
+    scaled: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    d_exp(d_ln(scaled, terms)? - value, terms)
+}
+
+/// (x/2)^ν * sum(k=0; s^k * (x/2)^2k / (k! * Γ(k + ν + 1))), with s = -1 for J_ν(x) and s = 1 for I_ν(x)
+/// t_k = s * t_k-1 * (x/2)^2 / (k * (k + ν)), from t_0 = (x/2)^ν / Γ(ν + 1)
+#[inline]
+fn bessel_series(
+    order: Decimal,
+    value: Decimal,
+    sign: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    let half = value / D2;
+    let lead = if order.fract().is_zero() && (order >= D0) {
+        let n = order.to_usize().ok_or(ErrorKind::OptionInvalid)?;
+        let mut acc: (Multiplex, usize) = (Multiplex::new(), 0);
+        (ma_pow(half, n, &mut acc)? / m_fac(n)?).squash()?
+    }
+    else {
+        // (x/2)^ν / Γ(ν + 1) = ±e^(ν * ln(x/2) - ln|Γ(ν + 1)|)
+        let shifted = order + D1;
+        let gamma_sign = if (shifted > D0) || (shifted.floor() % D2).is_zero() {D1} else {-D1};
+        let ln = order.checked_mul(d_ln(half, terms)?).ok_or(ErrorKind::MultiplyOverflow)?;
+        gamma_sign * d_exp(ln - d_lgamma(shifted, terms)?, terms)?
+    };
+    let sqr = sign * half.checked_mul(half).ok_or(ErrorKind::MultiplyOverflow)?;
+    // Set Variables
+    let mut term = lead;
+    let mut sum = lead;
+    for k in 1..SERIES_ITER_UPPER_BD {
+        let kd = dec(k);
+        term = term.checked_mul(sqr / (kd * (kd + order))).ok_or(ErrorKind::MultiplyOverflow)?;
+        sum = sum.checked_add(term).ok_or(ErrorKind::AddOverflow)?;
+        if term.abs() <= (sum.abs() * TOLERANCE) { return Ok(sum) };
+    };
+    Err(ErrorKind::NotConverged.into())
+}
+
+/// P = sum(k=0; -1^k * a_2k / x^2k), Q = sum(k=0; -1^k * a_2k+1 / x^(2k + 1))
+/// a_k = (4ν^2 - 1) * (4ν^2 - 9) * ... * (4ν^2 - (2k - 1)^2) / (k! * 8^k)
+/// J_ν(x) = sqrt(2 / (πx)) * (P * cos(χ) - Q * sin(χ)), with χ = x - (ν/2 + 1/4)π
+/// Y_ν(x) = sqrt(2 / (πx)) * (P * sin(χ) + Q * cos(χ))
+/// None where the terms stop shrinking above the precision of a Decimal
+#[inline]
+fn hankel(
+    order: Decimal,
+    value: Decimal,
+    terms: usize
+) -> Result<Option<(Decimal, Decimal)>, Error> {
+    let mu = D4 * order * order;
+    // Set Variables
+    let mut p = D1;
+    let mut q = D0;
+    let mut term = D1;
+    let mut converged = false;
+    for k in 1..SERIES_ITER_UPPER_BD {
+        let odd = dec((2 * k) - 1);
+        let next = term * (mu - (odd * odd)) / (D8 * dec(k)) / value;
+        if next.abs() >= term.abs() { break };
+        term = next;
+        match k % 4 {
+            0 => { p += term },
+            1 => { q += term },
+            2 => { p -= term },
+            _ => { q -= term },
+        };
+        if term.abs() < TOLERANCE { converged = true; break };
+    };
+    if !converged { return Ok(None) };
+    let chi = value.checked_sub((order * PIDIV2) + PIDIV4).ok_or(ErrorKind::AddOverflow)?;
+    let (cos, sin) = (d_cos(chi, terms)?, d_sin(chi, terms)?);
+    let amp = d_sqrt(D2 / PI, terms)? / d_sqrt(value, terms)?;
+    Ok(Some((amp * ((p * cos) - (q * sin)), amp * ((p * sin) + (q * cos)))))
+}
+
+/// J_μ+k(x) for 0 <= μ < 1, by Miller's backward recurrence from far above max(x, n)
+/// f_k-1 = 2(μ + k)/x * f_k - f_k+1
+/// (x/2)^μ / Γ(μ + 1) = f_0 + sum(k=1; (μ + 2k) * (μ + 1) * ... * (μ + k - 1) / k! * f_2k)
+#[inline]
+fn miller(
+    base: Decimal,
+    value: Decimal,
+    top: usize,
+    terms: usize
+) -> Result<Vec<Decimal>, Error> {
+    let far = value.ceil().to_usize().ok_or(ErrorKind::OptionInvalid)?.max(top);
+    let start = 2 * (far + MILLER_EXTRA);
+    if start > MILLER_ITER_UPPER_BD { Err(ErrorKind::InputOutOfRange)? };
+    // Set Variables
+    let mut f = vec![D0; start + 2];
+    f[start] = D1;
+    for k in (1..=start).rev() {
+        let prod = (D2 * (base + dec(k)) / value).checked_mul(f[k]).ok_or(ErrorKind::MultiplyOverflow)?;
+        f[k - 1] = prod - f[k + 1];
+        if f[k - 1].abs() > RESCALE_UPPER_BD {
+            for v in f[(k - 1)..].iter_mut() { *v *= RESCALE };
+        };
+    };
+    // Normalize
+    let mut norm = f[0];
+    let mut coef = D1;
+    for k in 1..=(start / 2) {
+        if k > 1 { coef = coef * (base + dec(k - 1)) / dec(k) };
+        norm += (base + dec(2 * k)) * coef * f[2 * k];
+    };
+    let lead = if base.is_zero() {D1} else {
+        d_exp(base * d_ln(value / D2, terms)?, terms)? * d_rgamma(base + D1, terms)?
+    };
+    // f_k / norm before the product, as 1 / norm may fall below the precision of a Decimal
+    Ok(f[..=start].iter().map(|v| v / norm * lead).collect())
+}
+
+//##########################################################################################################################
+
+/// J_ν(x) for x > 0
+#[inline]
+fn j_positive(
+    order: Decimal,
+    value: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    // J_-n(x) = (-1)^n * J_n(x)
+    if order.fract().is_zero() && (order < D0) {
+        let res = j_positive(-order, value, terms)?;
+        return Ok(if (order % D2).is_zero() {res} else {-res})
+    };
+    if value < SERIES_UPPER_BD { return bessel_series(order, value, -D1, terms) };
+    if value >= HANKEL_LOWER_BD {
+        if let Some((res, _)) = hankel(order, value, terms)? { return Ok(res) };
+    };
+    let floor = order.floor();
+    let base = order - floor;
+    if floor >= D0 {
+        let top = floor.to_usize().ok_or(ErrorKind::OptionInvalid)?;
+        return Ok(miller(base, value, top, terms)?[top])
+    };
+    // J_μ-1(x) = 2μ/x * J_μ(x) - J_μ+1(x), growing toward negative orders
+    let f = miller(base, value, 1, terms)?;
+    let (mut prev, mut res) = (f[1], f[0]);
+    let mut k = base;
+    while k > order {
+        let next = (D2 * k / value).checked_mul(res).ok_or(ErrorKind::MultiplyOverflow)? - prev;
+        (prev, res) = (res, next);
+        k -= D1;
+    };
+    Ok(res)
+}
+
+/// J_0(x), J_1(x), ..., far past the orders where they fall below the precision of a Decimal
+#[inline]
+fn j_orders(
+    value: Decimal,
+    terms: usize
+) -> Result<Vec<Decimal>, Error> {
+    if value >= SERIES_UPPER_BD { return miller(D0, value, 0, terms) };
+    let far = value.ceil().to_usize().ok_or(ErrorKind::OptionInvalid)?;
+    (0..=(2 * (far + MILLER_EXTRA)))
+        .map(|k| bessel_series(dec(k), value, -D1, terms))
+        .collect()
+}
+
+/// Y_0(x) and Y_1(x) for x > 0, by Neumann's expansion in J_2k(x) and J_2k+1(x)
+/// π/2 * Y_0(x) = (ln(x/2) + γ) * J_0(x) - 2 * sum(k=1; -1^k * J_2k(x) / k)
+/// π/2 * Y_1(x) = (ln(x/2) + γ - 1) * J_1(x) - J_0(x) / x - sum(k=1; -1^k * (2k + 1) / (k * (k + 1)) * J_2k+1(x))
+#[inline]
+fn y_unit(
+    value: Decimal,
+    terms: usize
+) -> Result<(Decimal, Decimal), Error> {
+    if value >= HANKEL_LOWER_BD {
+        if let (Some((_, y0)), Some((_, y1))) = (hankel(D0, value, terms)?, hankel(D1, value, terms)?) {
+            return Ok((y0, y1))
+        };
+    };
+    let j = j_orders(value, terms)?;
+    let ln = d_ln(value / D2, terms)? + EULER_GAMMA;
+    // Set Variables
+    let mut s0 = D0;
+    let mut s1 = D0;
+    let mut sgn = -D1;
+    for k in 1..(j.len() / 2) {
+        let kd = dec(k);
+        s0 += sgn * j[2 * k] / kd;
+        s1 += sgn * dec((2 * k) + 1) / (kd * (kd + D1)) * j[(2 * k) + 1];
+        sgn = -sgn;
+    };
+    let y0 = ((ln * j[0]) - (D2 * s0)) / PIDIV2;
+    let y1 = (((ln - D1) * j[1]) - (j[0] / value) - s1) / PIDIV2;
+    Ok((y0, y1))
+}
+
+/// Y_ν(x) for x > 0
+#[inline]
+fn y_positive(
+    order: Decimal,
+    value: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    if order.fract().is_zero() {
+        // Y_n+1(x) = 2n/x * Y_n(x) - Y_n-1(x), stable toward higher orders
+        let n = order.abs().to_usize().ok_or(ErrorKind::OptionInvalid)?;
+        let (mut prev, mut res) = y_unit(value, terms)?;
+        if n == 0 { return Ok(prev) };
+        for k in 1..n {
+            let next = (D2 * dec(k) / value).checked_mul(res).ok_or(ErrorKind::MultiplyOverflow)? - prev;
+            (prev, res) = (res, next);
+        };
+        // Y_-n(x) = (-1)^n * Y_n(x)
+        return Ok(if (order >= D0) || (order % D2).is_zero() {res} else {-res})
+    };
+    if value >= HANKEL_LOWER_BD {
+        if let Some((_, res)) = hankel(order, value, terms)? { return Ok(res) };
+    };
+    // Y_ν(x) = (J_ν(x) * cos(νπ) - J_-ν(x)) / sin(νπ)
+    let j = j_positive(order, value, terms)?;
+    let jn = j_positive(-order, value, terms)?;
+    let num = j.checked_mul(cos_pi(order, terms)?).ok_or(ErrorKind::MultiplyOverflow)? - jn;
+    num.checked_div(sin_pi(order, terms)?).ok_or(ErrorKind::MultiplyOverflow.into())
+}
+
+//##########################################################################################################################
+
+/// I_ν(x) for x > 0
+#[inline]
+fn i_positive(
+    order: Decimal,
+    value: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    // I_-n(x) = I_n(x)
+    let order = if order.fract().is_zero() {order.abs()} else {order};
+    bessel_series(order, value, D1, terms)
+}
+
+/// sum(k=0; s^k * x^2k / (2k + 1)!), with s = 1 for sinh(x)/x and s = -1 for sin(x)/x
+#[inline]
+fn sinc_series(
+    value: Decimal,
+    sign: Decimal
+) -> Result<Decimal, Error> {
+    let sqr = sign * value.checked_mul(value).ok_or(ErrorKind::MultiplyOverflow)?;
+    // Set Variables
+    let mut term = D1;
+    let mut sum = D1;
+    for k in 1..SERIES_ITER_UPPER_BD {
+        let kd = dec(2 * k);
+        term = term.checked_mul(sqr / (kd * (kd + D1))).ok_or(ErrorKind::MultiplyOverflow)?;
+        sum = sum.checked_add(term).ok_or(ErrorKind::AddOverflow)?;
+        if term.abs() <= (sum.abs() * TOLERANCE) { return Ok(sum) };
+    };
+    Err(ErrorKind::NotConverged.into())
+}
+
+/// Even and odd parts of 1/Γ(1 + μ) = sum(k=0; c_k * μ^k), as (sum(k=0; c_2k * μ^2k), sum(k=0; c_2k+1 * μ^2k))
+/// 1/Γ(1 ± μ) = even ± μ * odd, free of the cancellation in (1/Γ(1 - μ) - 1/Γ(1 + μ)) / 2μ = -odd
+#[inline]
+fn rgamma_parts(
+    value: Decimal
+) -> (Decimal, Decimal) {
+    let sqr = value * value;
+    let mut even = D0;
+    let mut odd = D0;
+    for k in (0..(RGAMMA_TAYLOR.len() / 2)).rev() {
+        even = (even * sqr) + RGAMMA_TAYLOR[2 * k];
+        odd = (odd * sqr) + RGAMMA_TAYLOR[(2 * k) + 1];
+    };
+    (even, odd)
+}
+
+/// K_μ(x) and K_μ+1(x) for |μ| <= 1/2 and 0 < x <= 2, by Temme's series, with σ = μ * ln(2/x) and c_k = (x/2)^2k / k!
+/// f_0 = μπ/sin(μπ) * (Γ_1 * cosh(σ) + Γ_2 * ln(2/x) * sinh(σ)/σ), Γ_1 = (1/Γ(1 - μ) - 1/Γ(1 + μ)) / 2μ, Γ_2 = (1/Γ(1 - μ) + 1/Γ(1 + μ)) / 2
+/// p_0 = e^σ * Γ(1 + μ) / 2, q_0 = e^-σ * Γ(1 - μ) / 2
+/// f_k = (k * f_k-1 + p_k-1 + q_k-1) / (k^2 - μ^2), p_k = p_k-1 / (k - μ), q_k = q_k-1 / (k + μ)
+/// K_μ(x) = sum(k=0; c_k * f_k), K_μ+1(x) = 2/x * sum(k=0; c_k * (p_k - k * f_k))
+#[inline]
+fn k_temme(
+    base: Decimal,
+    value: Decimal,
+    terms: usize
+) -> Result<(Decimal, Decimal), Error> {
+    let half = value / D2;
+    let ln = -d_ln(half, terms)?;
+    let sigma = base * ln;
+    let (even, odd) = rgamma_parts(base);
+    let fact = D1 / sinc_series(base * PI, -D1)?;
+    let ex = d_exp(sigma, terms)?;
+    let exn = D1 / ex;
+    // Set Variables
+    let sqr = half * half;
+    let mut f = fact * ((-odd * (ex + exn) / D2) + (even * ln * sinc_series(sigma, D1)?));
+    let mut p = ex / (D2 * (even + (base * odd)));
+    let mut q = exn / (D2 * (even - (base * odd)));
+    let mut c = D1;
+    let mut s0 = f;
+    let mut s1 = p;
+    for k in 1..SERIES_ITER_UPPER_BD {
+        let kd = dec(k);
+        f = ((kd * f) + p + q) / ((kd * kd) - (base * base));
+        c = c * sqr / kd;
+        p /= kd - base;
+        q /= kd + base;
+        let t0 = c * f;
+        let t1 = c * (p - (kd * f));
+        s0 += t0;
+        s1 += t1;
+        if (t0.abs() <= (s0.abs() * TOLERANCE)) && (t1.abs() <= (s1.abs() * TOLERANCE)) {
+            return Ok((s0, s1 / half))
+        };
+    };
+    Err(ErrorKind::NotConverged.into())
+}
+
+/// e^x * K_μ(x) and e^x * K_μ+1(x) for |μ| <= 1/2 and x > 2, by Steed's continued fraction
+/// The pair c_k * (q_k-1, q_k) and the sum of c_k * q_k are carried over a power of ten keeping the sum below one,
+/// as c_k grows past Decimal::MAX while Δh_k falls below the precision of a Decimal
+#[inline]
+fn k_fraction(
+    base: Decimal,
+    value: Decimal,
+    terms: usize
+) -> Result<(Decimal, Decimal), Error> {
+    let a1 = D1DIV4 - (base * base);
+    // Set Variables
+    let mut a = -a1;
+    let mut b = D2 * (D1 + value);
+    let mut d = D1 / b;
+    let mut delh = d;
+    let mut h = d;
+    let mut p1 = D0;
+    let mut p2 = a1;
+    let mut q = a1;
+    let mut dw = d;
+    let mut s = D1 + (q * dw);
+    for i in 1..FRACTION_ITER_UPPER_BD {
+        let id = dec(i);
+        a -= D2 * id;
+        let next = -(p1 - (b * p2)) / (id + D1);
+        p1 = -(a / (id + D1)) * p2;
+        p2 = next;
+        q += next;
+        b += D2;
+        d = D1 / (b + (a * d));
+        // Δh_k = (b_k * d_k - 1) * Δh_k-1
+        let ratio = (b * d) - D1;
+        delh = ratio * delh;
+        h += delh;
+        dw = ratio * dw;
+        while q.abs() > D1 {
+            (p1, p2, q) = (p1 / D10, p2 / D10, q / D10);
+            dw *= D10;
+        };
+        let dels = q * dw;
+        s += dels;
+        if dels.abs() <= (s.abs() * TOLERANCE) {
+            let k0 = d_sqrt(PI / (D2 * value), terms)? / s;
+            let k1 = k0 * (base + value + D1DIV2 - (a1 * h)) / value;
+            return Ok((k0, k1))
+        };
+    };
+    Err(ErrorKind::NotConverged.into())
+}
+
+/// K_ν(x) for ν >= 0 and x > 0
+#[inline]
+fn k_positive(
+    order: Decimal,
+    value: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    if value > K_UPPER_BD { return Ok(D0) };
+    // K_ν(x) from K_μ(x), with ν = n + μ
+    let base = order - order.round();
+    if value <= K_SERIES_UPPER_BD {
+        let (k0, k1) = k_temme(base, value, terms)?;
+        let (res, shift) = k_forward(base, value, (k0, k1), order)?;
+        return if shift.is_zero() { Ok(res) } else { exp_shift(-shift, res, terms) }
+    };
+    // e^x * K_ν(x) from e^x * K_μ(x)
+    let (k0, k1) = k_fraction(base, value, terms)?;
+    let (res, shift) = k_forward(base, value, (k0, k1), order)?;
+    exp_shift(value - shift, res, terms)
+}
+
+/// K_μ+k+1(x) = 2(μ + k)/x * K_μ+k(x) + K_μ+k-1(x), stable toward higher orders
+/// Returns K_ν(x) over e^y and y, rescaled as it grows past the bound
+#[inline]
+fn k_forward(
+    base: Decimal,
+    value: Decimal,
+    unit: (Decimal, Decimal),
+    order: Decimal
+) -> Result<(Decimal, Decimal), Error> {
+    let (mut prev, mut res) = unit;
+    if order == base { return Ok((prev, D0)) };
+    // Set Variables
+    let mut shift = D0;
+    let mut k = base + D1;
+    while k < order {
+        let next = (D2 * k / value).checked_mul(res).ok_or(ErrorKind::MultiplyOverflow)?
+            .checked_add(prev).ok_or(ErrorKind::AddOverflow)?;
+        (prev, res) = (res, next);
+        if res > RESCALE_UPPER_BD {
+            (prev, res) = (prev * RESCALE, res * RESCALE);
+            shift += LN_RESCALE;
+        };
+        k += D1;
+    };
+    Ok((res, shift))
+}
+
+//##########################################################################################################################
+
+/// J_ν(x) = sum(k=0; -1^k * (x/2)^(2k + ν) / (k! * Γ(k + ν + 1)))
+/// J_ν(x) = sqrt(2 / (πx)) * (P * cos(χ) - Q * sin(χ)) for large x
+pub fn d_bessel_j(
+    value: Decimal,
+    arg: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    let ctx = |e: Error| e.context("d_bessel_j", format!("{}, {}", value, arg));
+    if value.abs() > ORDER_UPPER_BD { Err(ctx(ErrorKind::InputOutOfRange.into()))? };
+    let int = value.fract().is_zero();
+    if arg.is_zero() {
+        return if value.is_zero() { Ok(D1) } else if int || (value > D0) { Ok(D0) } else { Err(ctx(ErrorKind::Pole.into())) }
+    };
+    if arg > D0 { return j_positive(value, arg, terms).map_err(ctx) };
+    // J_n(-x) = (-1)^n * J_n(x), complex for real orders
+    if !int { Err(ctx(ErrorKind::InputOutOfRange.into()))? };
+    let res = j_positive(value, -arg, terms).map_err(ctx)?;
+    Ok(if (value % D2).is_zero() {res} else {-res})
+}
+
+/// Y_ν(x) = (J_ν(x) * cos(νπ) - J_-ν(x)) / sin(νπ), and its limit for integer orders
+/// Y_ν(x) = sqrt(2 / (πx)) * (P * sin(χ) + Q * cos(χ)) for large x
+pub fn d_bessel_y(
+    value: Decimal,
+    arg: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    let ctx = |e: Error| e.context("d_bessel_y", format!("{}, {}", value, arg));
+    if value.abs() > ORDER_UPPER_BD { Err(ctx(ErrorKind::InputOutOfRange.into()))? };
+    if arg.is_zero() { Err(ctx(ErrorKind::Pole.into()))? };
+    if arg < D0 { Err(ctx(ErrorKind::InputOutOfRange.into()))? };
+    y_positive(value, arg, terms).map_err(ctx)
+}
+
+/// I_ν(x) = sum(k=0; (x/2)^(2k + ν) / (k! * Γ(k + ν + 1)))
+pub fn d_bessel_i(
+    value: Decimal,
+    arg: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    let ctx = |e: Error| e.context("d_bessel_i", format!("{}, {}", value, arg));
+    if value.abs() > ORDER_UPPER_BD { Err(ctx(ErrorKind::InputOutOfRange.into()))? };
+    let int = value.fract().is_zero();
+    if arg.is_zero() {
+        return if value.is_zero() { Ok(D1) } else if int || (value > D0) { Ok(D0) } else { Err(ctx(ErrorKind::Pole.into())) }
+    };
+    if arg > D0 { return i_positive(value, arg, terms).map_err(ctx) };
+    // I_n(-x) = (-1)^n * I_n(x), complex for real orders
+    if !int { Err(ctx(ErrorKind::InputOutOfRange.into()))? };
+    let res = i_positive(value, -arg, terms).map_err(ctx)?;
+    Ok(if (value % D2).is_zero() {res} else {-res})
+}
+
+/// K_ν(x) = π/2 * (I_-ν(x) - I_ν(x)) / sin(νπ), and its limit for integer orders
+/// K_ν(x) by Temme's series for x <= 2 and Steed's continued fraction above, with K_-ν(x) = K_ν(x)
+pub fn d_bessel_k(
+    value: Decimal,
+    arg: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    let ctx = |e: Error| e.context("d_bessel_k", format!("{}, {}", value, arg));
+    if value.abs() > ORDER_UPPER_BD { Err(ctx(ErrorKind::InputOutOfRange.into()))? };
+    if arg.is_zero() { Err(ctx(ErrorKind::Pole.into()))? };
+    if arg < D0 { Err(ctx(ErrorKind::InputOutOfRange.into()))? };
+    k_positive(value.abs(), arg, terms).map_err(ctx)
+}
+
+//##########################################################################################################################
@@ -0,0 +1,182 @@
+
+// Imports
+use rust_decimal::prelude::*;
+use rust_decimal_macros::dec;
+
+// Modules
+use crate::error::{ Error, ErrorKind };
+
+use crate::factorial::{ m_fac };
+use crate::euler::{ d_exp, d_ln };
+use crate::special::gamma::{ d_gamma, d_lgamma, INC_UPPER_BD, INC_ITER_UPPER_BD, INC_TOLERANCE, INC_TINY };
+
+//##########################################################################################################################
+
+// Constants
+const D0: Decimal = Decimal::ZERO;
+const D1: Decimal = Decimal::ONE;
+const D2: Decimal = Decimal::TWO;
+
+// Largest a + b with Γ(a + b) below Decimal::MAX
+const GAMMA_UPPER_BD: Decimal = dec!(29);
+
+// Largest a + b taken through the binomial coefficient, past which B(a, b) underflows
+const BINOMIAL_UPPER_BD: Decimal = dec!(65536);
+
+// Largest a + b - 1 whose factorial m_fac provides
+const FACTORIAL_UPPER_BD: usize = 255;
+
+//##########################################################################################################################
+
+/// C(n, k) on u128, exact or None on overflow
+#[inline]
+fn u_binomial(
+    value: usize,
+    other: usize
+) -> Option<u128> {
+    let k = other.min(value - other) as u128;
+    let n = value as u128;
+    // C(n - k + i, i) = C(n - k + i - 1, i - 1) * (n - k + i) / i is exact at each step
+    (1..=k).try_fold(1_u128, |acc, i| Some(acc.checked_mul(n - k + i)? / i))
+}
+
+/// 1 / B(a, b) = (a + b - 1) * C(a + b - 2, a - 1) for positive integers, or None past Decimal::MAX
+/// Exact on u128 while it fits, which keeps every digit, and otherwise the quotient of m_fac by squash()
+#[inline]
+fn beta_inv_integer(
+    value: usize,
+    other: usize
+) -> Result<Option<Decimal>, Error> {
+    let sum = value + other;
+    let exact = u_binomial(sum - 2, value - 1).and_then(|c| c.checked_mul((sum - 1) as u128));
+    if let Some(inv) = exact { return Ok(Decimal::from_u128(inv)) };
+    if (sum - 1) > FACTORIAL_UPPER_BD { return Ok(None) };
+    match (m_fac(sum - 1)? / (m_fac(value - 1)? * m_fac(other - 1)?)).squash() {
+        Err(err) if err.kind() == ErrorKind::MultiplyOverflow => Ok(None),
+        res => res.map(Some),
+    }
+}
+
+/// Sign of Γ(x), for x not a pole
+#[inline]
+fn gamma_sign(
+    value: Decimal
+) -> Decimal {
+    if (value > D0) || (value.floor() % D2).is_zero() {D1} else {-D1}
+}
+
+/// ln|B(a, b)| = ln|Γ(a)| + ln|Γ(b)| - ln|Γ(a + b)|
+#[inline]
+fn lbeta(
+    value: Decimal,
+    other: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    Ok(d_lgamma(value, terms)? + d_lgamma(other, terms)? - d_lgamma(value + other, terms)?)
+}
+
+//##########################################################################################################################
+
+/// B(a, b) = 1 / ((a + b - 1) * C(a + b - 2, a - 1)) for positive integers
+/// B(a, b) = Γ(a) * Γ(b) / Γ(a + b) otherwise
+/// B(a, b) = 0 below the scale of a Decimal
+pub fn d_beta(
+    value: Decimal,
+    other: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    let ctx = |e: Error| e.context("d_beta", format!("{}, {}", value, other));
+    let pole = |v: Decimal| v.fract().is_zero() && (v <= D0);
+    if pole(value) || pole(other) { Err(ctx(ErrorKind::Pole.into()))? };
+    // Γ(a + b) has a pole where Γ(a) and Γ(b) do not
+    let sum = value.checked_add(other).ok_or(ctx(ErrorKind::AddOverflow.into()))?;
+    if pole(sum) { return Ok(D0) };
+    // Binomial coefficient of positive integers
+    if value.fract().is_zero() && other.fract().is_zero() && (sum <= BINOMIAL_UPPER_BD) {
+        let a = value.to_usize().ok_or(ctx(ErrorKind::OptionInvalid.into()))?;
+        let b = other.to_usize().ok_or(ctx(ErrorKind::OptionInvalid.into()))?;
+        let inv = beta_inv_integer(a, b).map_err(ctx)?;
+        // 1 / B(a, b) past Decimal::MAX leaves B(a, b) below the scale of a Decimal
+        return Ok(inv.map_or(D0, |inv| D1 / inv))
+    };
+    // Γ(a) * Γ(b) before the division keeps the digits of small results
+    if (value.abs() < GAMMA_UPPER_BD) && (other.abs() < GAMMA_UPPER_BD) && (sum.abs() < GAMMA_UPPER_BD) {
+        let ga = d_gamma(value, terms).map_err(ctx)?;
+        let gb = d_gamma(other, terms).map_err(ctx)?;
+        let gs = d_gamma(sum, terms).map_err(ctx)?;
+        if let Some(prod) = ga.checked_mul(gb) { return Ok(prod / gs) };
+        return (ga / gs).checked_mul(gb).ok_or(ctx(ErrorKind::MultiplyOverflow.into()))
+    };
+    let sign = gamma_sign(value) * gamma_sign(other) * gamma_sign(sum);
+    Ok(sign * d_exp(lbeta(value, other, terms).map_err(ctx)?, terms).map_err(ctx)?)
+}
+
+//##########################################################################################################################
+
+/// 1 / (1 + d_1 / (1 + d_2 / (1 + ...))), by Lentz's method
+/// d_2m = m * (b - m) * x / ((a + 2m - 1) * (a + 2m))
+/// d_2m+1 = -(a + m) * (a + b + m) * x / ((a + 2m) * (a + 2m + 1))
+#[inline]
+fn beta_inc_fraction(
+    value: Decimal,
+    other: Decimal,
+    arg: Decimal
+) -> Result<Decimal, Error> {
+    let tiny = |v: Decimal| if v.abs() < INC_TINY {INC_TINY} else {v};
+    let sum = value + other;
+    // Set Variables
+    let mut c = D1;
+    let mut d = D1 / tiny(D1 - (sum * arg / (value + D1)));
+    let mut h = d;
+    for i in 1..INC_ITER_UPPER_BD {
+        let m = Decimal::from(i);
+        let m2 = m * D2;
+        // Even step
+        let a = m * (other - m) * arg / ((value + m2 - D1) * (value + m2));
+        d = D1 / tiny(D1 + (a * d));
+        c = tiny(D1 + (a / c));
+        h = h.checked_mul(d * c).ok_or(ErrorKind::MultiplyOverflow)?;
+        // Odd step
+        let a = -(value + m) * (sum + m) * arg / ((value + m2) * (value + m2 + D1));
+        d = D1 / tiny(D1 + (a * d));
+        c = tiny(D1 + (a / c));
+        let delta = d * c;
+        h = h.checked_mul(delta).ok_or(ErrorKind::MultiplyOverflow)?;
+        if (delta - D1).abs() < INC_TOLERANCE { return Ok(h) };
+    };
+    Err(ErrorKind::NotConverged.into())
+}
+
+/// x^a * (1 - x)^b / (a * B(a, b)) * fraction(a, b, x), for x < (a + 1) / (a + b + 2)
+#[inline]
+fn beta_inc_helper(
+    value: Decimal,
+    other: Decimal,
+    arg: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    let ln_x = value.checked_mul(d_ln(arg, terms)?).ok_or(ErrorKind::MultiplyOverflow)?;
+    let ln_y = other.checked_mul(d_ln(D1 - arg, terms)?).ok_or(ErrorKind::MultiplyOverflow)?;
+    let prefix = d_exp(ln_x + ln_y - lbeta(value, other, terms)?, terms)?;
+    let frac = beta_inc_fraction(value, other, arg)?;
+    Ok(prefix.checked_mul(frac).ok_or(ErrorKind::MultiplyOverflow)? / value)
+}
+
+/// I_x(a, b) = B(x; a, b) / B(a, b)
+/// I_x(a, b) = 1 - I_(1 - x)(b, a) for x >= (a + 1) / (a + b + 2)
+pub fn d_beta_inc(
+    value: Decimal,
+    other: Decimal,
+    arg: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    let ctx = |e: Error| e.context("d_beta_inc", format!("{}, {}, {}", value, other, arg));
+    let range = |v: Decimal| (v > D0) && (v <= INC_UPPER_BD);
+    if !range(value) || !range(other) || (arg < D0) || (arg > D1) { Err(ctx(ErrorKind::InputOutOfRange.into()))? };
+    if arg.is_zero() { return Ok(D0) };
+    if arg == D1 { return Ok(D1) };
+    if arg < ((value + D1) / (value + other + D2)) { beta_inc_helper(value, other, arg, terms).map_err(ctx) }
+    else { Ok(D1 - beta_inc_helper(other, value, D1 - arg, terms).map_err(ctx)?) }
+}
+
+//##########################################################################################################################
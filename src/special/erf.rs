@@ -0,0 +1,309 @@
+
+// Imports
+use rust_decimal::prelude::*;
+use rust_decimal_macros::dec;
+
+// Modules
+use crate::constants::{ SQRT_PI, D2DIV_SQRT_PI };
+use crate::error::{ Error, ErrorKind };
+
+use crate::basic::{ Terms, dec, da_pow, d_series };
+use crate::factorial::{ m_fac };
+use crate::euler::{ d_exp_helper, d_ln_helper };
+
+use crate::multiplex::types::{ Multiplex };
+use crate::multiplex::basic::{ ma_pow };
+
+//##########################################################################################################################
+
+// Constants
+const D0: Decimal = Decimal::ZERO;
+const D1: Decimal = Decimal::ONE;
+const D2: Decimal = Decimal::TWO;
+const D1DIV2: Decimal = dec!(0.5);
+
+// Below this bound erf(x) uses the Maclaurin series, and above it the continued fraction of erfc(x)
+const SERIES_UPPER_BD: Decimal = dec!(1.5);
+
+// Above this bound erfcx(x) = 1 / (x * sqrt(π)) to the precision of a Decimal
+const ASYMPTOTIC_LOWER_BD: Decimal = dec!(100_000_000_000_000);
+
+// Largest number of steps in the continued fraction and the inverse
+const FRACTION_ITER_UPPER_BD: usize = 10_000;
+const INVERSE_ITER_UPPER_BD: usize = 64;
+
+// Relative precision where the continued fraction and the inverse stop
+const TOLERANCE: Decimal = dec!(0.000000000000000000000000001);
+
+// Floor of the denominators in Lentz's method
+const TINY: Decimal = dec!(0.0000000000000000000000000001);
+
+//##########################################################################################################################
+
+/// erf(x) = 2/sqrt(π) * sum(n=0; -1^n * (x^(2n + 1) / (n! * (2n + 1))))
+#[inline]
+fn erf_series(
+    value: Decimal,
+    terms: Terms
+) -> Result<(Decimal, usize), Error> {
+    let mut acc1: (Decimal, usize) = (D1, 0);
+    let mut acc2: (Multiplex, usize) = (Multiplex::new(), 0);
+    // Iterate over Series
+    let (sum, used) = d_series(0, terms, |n| Ok(
+        da_pow(-D1, n, &mut acc1)? * (
+            ma_pow(value, (2 * n) + 1, &mut acc2)? / (m_fac(n)? * dec((2 * n) + 1))
+        ).squash()?
+    ))?;
+    Ok((D2DIV_SQRT_PI * sum, used))
+}
+
+/// sqrt(π) * erfcx(x) = 1 / (x + (1/2) / (x + 1 / (x + (3/2) / (x + ...)))), by Lentz's method
+#[inline]
+fn erfcx_fraction(
+    value: Decimal
+) -> Result<(Decimal, usize), Error> {
+    let tiny = |v: Decimal| if v.abs() < TINY {TINY} else {v};
+    // Set Variables
+    let mut c = value;
+    let mut d = D0;
+    let mut f = value;
+    for i in 1..FRACTION_ITER_UPPER_BD {
+        let a = dec(i) * D1DIV2;
+        d = D1 / tiny(value + (a * d));
+        c = tiny(value + (a / c));
+        let delta = c * d;
+        f = f.checked_mul(delta).ok_or(ErrorKind::MultiplyOverflow)?;
+        if (delta - D1).abs() < TOLERANCE { return Ok((D1 / f, i)) };
+    };
+    Err(ErrorKind::NotConverged.into())
+}
+
+/// e^(-x^2), with underflow taken as zero
+#[inline]
+fn exp_neg_sqr(
+    value: Decimal,
+    terms: Terms
+) -> Result<Decimal, Error> {
+    let sqr = match value.checked_mul(value) { Some(v) => v, None => return Ok(D0) };
+    Ok(d_exp_helper(-sqr, terms)?.0)
+}
+
+/// erfc(x) for x > 0
+#[inline]
+fn erfc_positive(
+    value: Decimal,
+    terms: Terms
+) -> Result<(Decimal, usize), Error> {
+    if value < SERIES_UPPER_BD {
+        let (res, used) = erf_series(value, terms)?;
+        return Ok((D1 - res, used))
+    };
+    let exp = exp_neg_sqr(value, terms)?;
+    if exp == D0 { return Ok((D0, 0)) };
+    let (frac, used) = erfcx_fraction(value)?;
+    Ok(((exp * frac) / SQRT_PI, used))
+}
+
+//##########################################################################################################################
+
+#[inline]
+fn d_erf_helper(
+    value: Decimal,
+    terms: Terms
+) -> Result<(Decimal, usize), Error> {
+    Ok(
+             if value == D0  { (D0, 0) }
+        else if value <  D0  { let (res, used) = d_erf_helper(-value, terms)?; (-res, used) }
+        else if value <  SERIES_UPPER_BD { erf_series(value, terms)? }
+        else { let (res, used) = erfc_positive(value, terms)?; (D1 - res, used) }
+    )
+}
+
+/// erf(x) = 2/sqrt(π) * integral(t=0; x; e^(-t^2))
+#[inline]
+pub fn d_erf(
+    value: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    d_erf_helper(value, Terms::Fixed(terms))
+        .map(|(res, _)| res)
+        .map_err(|e| e.context("d_erf", value))
+}
+
+/// erf(x) to `digits` decimal places, with the number of series terms used.
+#[inline]
+pub fn d_erf_dp(
+    value: Decimal,
+    digits: u32
+) -> Result<(Decimal, usize), Error> {
+    if digits > 28 { Err(Error::from(ErrorKind::InputOutOfRange).context("d_erf_dp", digits))? };
+    d_erf_helper(value, Terms::Digits(digits))
+        .map_err(|e| e.context("d_erf_dp", value))
+}
+
+//##########################################################################################################################
+
+#[inline]
+fn d_erfc_helper(
+    value: Decimal,
+    terms: Terms
+) -> Result<(Decimal, usize), Error> {
+    Ok(
+             if value == D0 { (D1, 0) }
+        else if value <  D0 { let (res, used) = erfc_positive(-value, terms)?; (D2 - res, used) }
+        else { erfc_positive(value, terms)? }
+    )
+}
+
+/// erfc(x) = 1 - erf(x), by the continued fraction for large x
+#[inline]
+pub fn d_erfc(
+    value: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    d_erfc_helper(value, Terms::Fixed(terms))
+        .map(|(res, _)| res)
+        .map_err(|e| e.context("d_erfc", value))
+}
+
+/// erfc(x) to `digits` decimal places, with the number of series terms used.
+#[inline]
+pub fn d_erfc_dp(
+    value: Decimal,
+    digits: u32
+) -> Result<(Decimal, usize), Error> {
+    if digits > 28 { Err(Error::from(ErrorKind::InputOutOfRange).context("d_erfc_dp", digits))? };
+    d_erfc_helper(value, Terms::Digits(digits))
+        .map_err(|e| e.context("d_erfc_dp", value))
+}
+
+//##########################################################################################################################
+
+#[inline]
+fn d_erfcx_helper(
+    value: Decimal,
+    terms: Terms
+) -> Result<(Decimal, usize), Error> {
+    // erfcx(x) = 1 / (x * sqrt(π)) * (1 - 1/(2x^2) + ...) where 1/(2x^2) is below the precision of a Decimal
+    if value >= ASYMPTOTIC_LOWER_BD { return Ok((D1 / value / SQRT_PI, 0)) };
+    if value >= SERIES_UPPER_BD {
+        let (frac, used) = erfcx_fraction(value)?;
+        return Ok((frac / SQRT_PI, used))
+    };
+    // e^(x^2) for x < 1.5, or the term 2 * e^(x^2) of erfcx(-x) = 2 * e^(x^2) - erfcx(x)
+    let sqr = value.checked_mul(value).ok_or(ErrorKind::MultiplyOverflow)?;
+    let (exp, _) = d_exp_helper(sqr, terms)?;
+    if value < D0 {
+        let (res, used) = d_erfcx_helper(-value, terms)?;
+        let exp2 = exp.checked_mul(D2).ok_or(ErrorKind::MultiplyOverflow)?;
+        return Ok((exp2 - res, used))
+    };
+    let (res, used) = erf_series(value, terms)?;
+    Ok((exp * (D1 - res), used))
+}
+
+/// erfcx(x) = e^(x^2) * erfc(x), by the continued fraction for large x
+#[inline]
+pub fn d_erfcx(
+    value: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    d_erfcx_helper(value, Terms::Fixed(terms))
+        .map(|(res, _)| res)
+        .map_err(|e| e.context("d_erfcx", value))
+}
+
+/// erfcx(x) to `digits` decimal places, with the number of series terms used.
+#[inline]
+pub fn d_erfcx_dp(
+    value: Decimal,
+    digits: u32
+) -> Result<(Decimal, usize), Error> {
+    if digits > 28 { Err(Error::from(ErrorKind::InputOutOfRange).context("d_erfcx_dp", digits))? };
+    d_erfcx_helper(value, Terms::Digits(digits))
+        .map_err(|e| e.context("d_erfcx_dp", value))
+}
+
+//##########################################################################################################################
+
+/// Initial guess of erfinv(x) from 1 - x^2, by Winitzki's approximation in f64
+#[inline]
+fn erfinv_guess(
+    value: Decimal
+) -> Result<Decimal, Error> {
+    let a = 0.147;
+    let ln = value.to_f64().ok_or(ErrorKind::OptionInvalid)?.ln();
+    let t = (2.0 / (std::f64::consts::PI * a)) + (ln / 2.0);
+    Decimal::from_f64((((t * t) - (ln / a)).sqrt() - t).sqrt()).ok_or(ErrorKind::OptionInvalid.into())
+}
+
+/// y = erfinv(x) for 0 < x < 1, by Newton's method
+/// y' = y - (erf(y) - x) * sqrt(π)/2 * e^(y^2) for x < 1/2
+/// y' = y + ln(erfc(y) / (1 - x)) * sqrt(π)/2 * erfcx(y) otherwise, keeping the digits of 1 - x
+#[inline]
+fn erfinv_positive(
+    value: Decimal,
+    terms: Terms
+) -> Result<(Decimal, usize), Error> {
+    let rem = D1 - value;
+    let (ln_rem, _) = d_ln_helper(rem, terms)?;
+    // Set Variables
+    let mut res = erfinv_guess(rem * (D2 - rem))?;
+    let mut last = Decimal::MAX;
+    for _ in 0..INVERSE_ITER_UPPER_BD {
+        let (step, used) = if value < D1DIV2 {
+            let (erf, used) = erf_series(res, terms)?;
+            let (exp, _) = d_exp_helper(res * res, terms)?;
+            ((value - erf) * exp * (SQRT_PI / D2), used)
+        }
+        else {
+            let (erfcx, used) = d_erfcx_helper(res, terms)?;
+            let (ln, _) = d_ln_helper(erfcx, terms)?;
+            ((ln - (res * res) - ln_rem) * erfcx * (SQRT_PI / D2), used)
+        };
+        // Stop at the precision of erf(y), where the steps stop shrinking
+        if step.abs() >= last { return Ok((res, used)) };
+        res += step;
+        last = step.abs();
+        if last < TOLERANCE { return Ok((res, used)) };
+    };
+    Err(ErrorKind::NotConverged.into())
+}
+
+#[inline]
+fn d_erfinv_helper(
+    value: Decimal,
+    terms: Terms
+) -> Result<(Decimal, usize), Error> {
+    if value.abs() >  D1 { Err(ErrorKind::InputOutOfRange)? };
+    if value.abs() == D1 { Err(ErrorKind::Pole)? };
+    Ok(
+             if value == D0 { (D0, 0) }
+        else if value <  D0 { let (res, used) = erfinv_positive(-value, terms)?; (-res, used) }
+        else { erfinv_positive(value, terms)? }
+    )
+}
+
+/// erfinv(x) = y such that erf(y) = x, for -1 < x < 1
+#[inline]
+pub fn d_erfinv(
+    value: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    d_erfinv_helper(value, Terms::Fixed(terms))
+        .map(|(res, _)| res)
+        .map_err(|e| e.context("d_erfinv", value))
+}
+
+/// erfinv(x) to `digits` decimal places, with the number of series terms used.
+#[inline]
+pub fn d_erfinv_dp(
+    value: Decimal,
+    digits: u32
+) -> Result<(Decimal, usize), Error> {
+    if digits > 28 { Err(Error::from(ErrorKind::InputOutOfRange).context("d_erfinv_dp", digits))? };
+    d_erfinv_helper(value, Terms::Digits(digits))
+        .map_err(|e| e.context("d_erfinv_dp", value))
+}
+
+//##########################################################################################################################
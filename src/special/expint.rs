@@ -0,0 +1,215 @@
+
+// Imports
+use rust_decimal::prelude::*;
+use rust_decimal_macros::dec;
+
+// Modules
+use crate::constants::{ EULER_GAMMA };
+use crate::error::{ Error, ErrorKind };
+
+use crate::basic::{ Terms, dec, da_pow, d_series };
+use crate::factorial::{ m_fac };
+use crate::euler::{ d_exp_helper, d_ln_helper };
+
+use crate::multiplex::types::{ Multiplex };
+use crate::multiplex::basic::{ ma_pow };
+
+//##########################################################################################################################
+
+// Constants
+const D0: Decimal = Decimal::ZERO;
+const D1: Decimal = Decimal::ONE;
+const D2: Decimal = Decimal::TWO;
+
+// li(2), the offset of Li(x)
+const LI_2: Decimal = dec!(1.0451637801174927848445888892);
+
+// Below this bound E1(x) uses the power series, and above it the continued fraction
+const SERIES_UPPER_BD: Decimal = dec!(2);
+
+// Above this bound E1(x) < e^-x / x falls below the scale of a Decimal
+const E1_UPPER_BD: Decimal = dec!(64);
+
+// Above this bound Ei(x) uses the asymptotic expansion, whose smallest term is below e^-x
+const ASYMPTOTIC_LOWER_BD: Decimal = dec!(64);
+
+// Largest number of terms in the series of Ei(x) and of steps in the continued fraction
+const SERIES_ITER_UPPER_BD: usize = 1_000;
+const FRACTION_ITER_UPPER_BD: usize = 10_000;
+
+// Relative precision where the series and the continued fraction stop
+const TOLERANCE: Decimal = dec!(0.000000000000000000000000001);
+
+// Floor of the denominators in Lentz's method
+const TINY: Decimal = dec!(0.0000000000000000000000000001);
+
+//##########################################################################################################################
+
+/// sum(n=1; x^n / (n * n!))
+#[inline]
+fn ein_series(
+    value: Decimal,
+    terms: Terms
+) -> Result<Decimal, Error> {
+    let mut acc1: (Decimal, usize) = (D1, 0);
+    let mut acc2: (Multiplex, usize) = (Multiplex::new(), 0);
+    // Iterate over Series
+    let (sum, _) = d_series(1, terms, |n| Ok(
+        da_pow(value.signum(), n, &mut acc1)? * (
+            ma_pow(value.abs(), n, &mut acc2)? / (m_fac(n)? * dec(n))
+        ).squash()?
+    ))?;
+    Ok(sum)
+}
+
+/// sum(n=1; x^n / (n * n!)) for large x > 0, with x^n / n! built up term by term
+#[inline]
+fn ein_positive(
+    value: Decimal
+) -> Result<Decimal, Error> {
+    // Set Variables
+    let mut coef: Decimal = D1;
+    let mut sum: Decimal = D0;
+    for n in 1..SERIES_ITER_UPPER_BD {
+        coef = coef.checked_mul(value / dec(n)).ok_or(ErrorKind::MultiplyOverflow)?;
+        let term = coef / dec(n);
+        sum = sum.checked_add(term).ok_or(ErrorKind::AddOverflow)?;
+        if term <= sum * TOLERANCE { return Ok(sum) };
+    };
+    Err(ErrorKind::NotConverged.into())
+}
+
+/// Ei(x) = e^x / x * sum(k=0; k! / x^k) for large x, as e^(x - ln(x)) to keep e^x within a Decimal
+#[inline]
+fn ei_asymptotic(
+    value: Decimal,
+    terms: Terms
+) -> Result<Decimal, Error> {
+    // Set Variables
+    let mut term: Decimal = D1;
+    let mut sum: Decimal = D1;
+    for k in 1..SERIES_ITER_UPPER_BD {
+        let next = term * (dec(k) / value);
+        if next >= term { break };
+        term = next;
+        sum += term;
+        if term <= sum * TOLERANCE { break };
+    };
+    let (ln, _) = d_ln_helper(value, terms)?;
+    let (exp, _) = d_exp_helper(value - ln, terms)?;
+    exp.checked_mul(sum).ok_or(ErrorKind::MultiplyOverflow.into())
+}
+
+/// e^x * E1(x) = 1 / (x + 1 - 1 / (x + 3 - 4 / (x + 5 - ...))), by Lentz's method
+#[inline]
+fn e1_fraction(
+    value: Decimal
+) -> Result<Decimal, Error> {
+    let tiny = |v: Decimal| if v.abs() < TINY {TINY} else {v};
+    // Set Variables
+    let mut b = value + D1;
+    let mut c = D1 / TINY;
+    let mut d = D1 / b;
+    let mut f = d;
+    for i in 1..FRACTION_ITER_UPPER_BD {
+        let a = -(dec(i) * dec(i));
+        b += D2;
+        d = D1 / tiny((a * d) + b);
+        c = tiny(b + (a / c));
+        let delta = c * d;
+        f *= delta;
+        if (delta - D1).abs() < TOLERANCE { return Ok(f) };
+    };
+    Err(ErrorKind::NotConverged.into())
+}
+
+/// E1(x) for x > 0
+#[inline]
+fn e1_positive(
+    value: Decimal,
+    terms: Terms
+) -> Result<Decimal, Error> {
+    if value > E1_UPPER_BD { return Ok(D0) };
+    if value <= SERIES_UPPER_BD {
+        // E1(x) = -γ - ln(x) - sum(n=1; (-x)^n / (n * n!))
+        let (ln, _) = d_ln_helper(value, terms)?;
+        let sum = ein_series(-value, terms)?;
+        return Ok(-EULER_GAMMA - ln - sum)
+    };
+    let frac = e1_fraction(value)?;
+    let (exp, _) = d_exp_helper(-value, terms)?;
+    Ok(exp * frac)
+}
+
+/// Ei(x) for x != 0
+#[inline]
+pub(crate) fn ei_helper(
+    value: Decimal,
+    terms: Terms
+) -> Result<Decimal, Error> {
+    // Ei(-x) = -E1(x)
+    if value < D0 { return Ok(-e1_positive(-value, terms)?) };
+    if value > ASYMPTOTIC_LOWER_BD { return ei_asymptotic(value, terms) };
+    // Ei(x) = γ + ln(x) + sum(n=1; x^n / (n * n!))
+    let (ln, _) = d_ln_helper(value, terms)?;
+    let sum =
+        if value <= SERIES_UPPER_BD { ein_series(value, terms)? }
+        else { ein_positive(value)? };
+    sum.checked_add(EULER_GAMMA + ln).ok_or(ErrorKind::AddOverflow.into())
+}
+
+//##########################################################################################################################
+
+/// Ei(x) = -integral(t=-x; inf; e^-t / t), as a principal value for x > 0
+#[inline]
+pub fn d_ei(
+    value: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    let ctx = |e: Error| e.context("d_ei", value);
+    if value == D0 { Err(ctx(ErrorKind::Pole.into()))? };
+    ei_helper(value, Terms::Fixed(terms)).map_err(ctx)
+}
+
+/// E1(x) = integral(t=x; inf; e^-t / t), for x > 0
+#[inline]
+pub fn d_e1(
+    value: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    let ctx = |e: Error| e.context("d_e1", value);
+    if value <  D0 { Err(ctx(ErrorKind::InputOutOfRange.into()))? };
+    if value == D0 { Err(ctx(ErrorKind::Pole.into()))? };
+    e1_positive(value, Terms::Fixed(terms)).map_err(ctx)
+}
+
+//##########################################################################################################################
+
+/// li(x) = integral(t=0; x; 1 / ln(t)) = Ei(ln(x)), for x >= 0
+#[inline]
+pub fn d_li(
+    value: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    let ctx = |e: Error| e.context("d_li", value);
+    if value <  D0 { Err(ctx(ErrorKind::InputOutOfRange.into()))? };
+    if value == D1 { Err(ctx(ErrorKind::Pole.into()))? };
+    if value == D0 { return Ok(D0) };
+    let (ln, _) = d_ln_helper(value, Terms::Fixed(terms)).map_err(ctx)?;
+    if ln == D0 { Err(ctx(ErrorKind::Pole.into()))? };
+    ei_helper(ln, Terms::Fixed(terms)).map_err(ctx)
+}
+
+/// Li(x) = integral(t=2; x; 1 / ln(t)) = li(x) - li(2), for x >= 0
+#[inline]
+#[allow(non_snake_case)]
+pub fn d_Li(
+    value: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    if value == D2 { return Ok(D0) };
+    let li = d_li(value, terms).map_err(|e| e.context("d_Li", value))?;
+    Ok(li - LI_2)
+}
+
+//##########################################################################################################################
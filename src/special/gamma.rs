@@ -0,0 +1,373 @@
+
+// Imports
+use rust_decimal::prelude::*;
+use rust_decimal_macros::dec;
+
+// Modules
+use crate::constants::{ PI, LN_PI, LN_SQRT_PI2 };
+use crate::error::{ Error, ErrorKind };
+
+use crate::factorial::{ d_fac };
+use crate::trigonometry::{ d_sin };
+use crate::euler::{ d_exp, d_ln };
+
+//##########################################################################################################################
+
+// Constants
+const D0: Decimal = Decimal::ZERO;
+const D1: Decimal = Decimal::ONE;
+const D2: Decimal = Decimal::TWO;
+const D1DIV2: Decimal = dec!(0.5);
+
+// Largest integer with Γ(n) = (n - 1)! below Decimal::MAX
+const GAMMA_INT_UPPER_BD: Decimal = dec!(28);
+
+// Γ(x) exceeds Decimal::MAX past this bound
+const GAMMA_UPPER_BD: Decimal = dec!(29);
+
+// Below this bound Γ(x) uses the reflection formula rather than the recurrence
+const REFLECT_UPPER_BD: Decimal = dec!(-20);
+
+// Smallest x where Stirling's series reaches full precision
+const STIRLING_LOWER_BD: Decimal = dec!(20);
+
+// Largest parameter or argument of the incomplete functions
+pub(crate) const INC_UPPER_BD: Decimal = dec!(1_000_000_000);
+
+// Largest number of terms in the series and continued fractions of the incomplete functions
+pub(crate) const INC_ITER_UPPER_BD: usize = 10_000;
+
+// Relative precision where the series and continued fractions stop
+pub(crate) const INC_TOLERANCE: Decimal = dec!(0.000000000000000000000000001);
+
+// Floor of the denominators in Lentz's method
+pub(crate) const INC_TINY: Decimal = dec!(0.0000000000000000000000000001);
+
+// B_2k / (2k * (2k - 1)) for k in 1..=14
+pub(crate) const STIRLING: [Decimal; 14] = [
+    dec!(0.0833333333333333333333333333),
+    dec!(-0.0027777777777777777777777778),
+    dec!(0.0007936507936507936507936508),
+    dec!(-0.0005952380952380952380952381),
+    dec!(0.0008417508417508417508417508),
+    dec!(-0.0019175269175269175269175269),
+    dec!(0.0064102564102564102564102564),
+    dec!(-0.0295506535947712418300653595),
+    dec!(0.1796443723688305731649384900),
+    dec!(-1.3924322169059011164274322169),
+    dec!(13.402864044168391994478951001),
+    dec!(-156.84828462600201730636513245),
+    dec!(2193.1033333333333333333333333),
+    dec!(-36108.771253724989357173265219),
+];
+
+//##########################################################################################################################
+
+/// sin(πx), reduced to the fractional part of x
+#[inline]
+pub(crate) fn sin_pi(
+    value: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    let floor = value.floor();
+    let sin = d_sin(PI * (value - floor), terms)?;
+    Ok(if (floor % D2).is_zero() {sin} else {-sin})
+}
+
+/// x + n in (0, 1) and x * (x + 1) * ... * (x + n - 1), for x < 0
+#[inline]
+fn shift_to_unit(
+    value: Decimal
+) -> (Decimal, Decimal) {
+    // Set Variables
+    let mut shifted = value;
+    let mut prod = D1;
+    while shifted < D0 {
+        prod *= shifted;
+        shifted += D1;
+    };
+    (shifted, prod)
+}
+
+//##########################################################################################################################
+
+/// ln(Γ(x)) = (x - 1/2) * ln(x) - x + ln(2π)/2 + sum(k=1; B_2k / (2k * (2k - 1) * x^(2k - 1))), for x >= 20
+#[inline]
+fn lgamma_stirling(
+    value: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    let ln = d_ln(value, terms)?;
+    let inv = D1 / value;
+    let inv_sqr = inv * inv;
+    // Set Variables
+    let mut pow = inv;
+    let mut sum = D0;
+    for c in STIRLING {
+        sum += c * pow;
+        pow *= inv_sqr;
+    };
+    let main = (value - D1DIV2).checked_mul(ln).ok_or(ErrorKind::MultiplyOverflow)?;
+    Ok(main - value + LN_SQRT_PI2 + sum)
+}
+
+/// ln(Γ(x)) = ln(Γ(x + n)) - ln(x * (x + 1) * ... * (x + n - 1)), for x > 0
+#[inline]
+fn lgamma_positive(
+    value: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    // ln(Γ(x)) = ln(Γ(x + 1)) - ln(x) keeps the digits of small x
+    if value < D1 { return Ok(lgamma_positive(value + D1, terms)? - d_ln(value, terms)?) };
+    // Set Variables
+    let mut shifted = value;
+    let mut prod = D1;
+    while shifted < STIRLING_LOWER_BD {
+        prod *= shifted;
+        shifted += D1;
+    };
+    Ok(lgamma_stirling(shifted, terms)? - d_ln(prod, terms)?)
+}
+
+/// Γ(x) = Γ(1 + f) * (1 + f) * (2 + f) * ... * (x - 1), for x = m + f > 0
+#[inline]
+fn gamma_positive(
+    value: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    if value > GAMMA_UPPER_BD { Err(ErrorKind::MultiplyOverflow)? };
+    let fract = value.fract();
+    // ln(Γ(1 + f)) is small, so e^x keeps every digit
+    let base = d_exp(lgamma_positive(D1 + fract, terms)?, terms)?;
+    if value < D1 { return base.checked_div(value).ok_or(ErrorKind::MultiplyOverflow.into()) };
+    // Set Variables
+    let mut acc = base;
+    let mut k = D1 + fract;
+    while k < value {
+        acc = acc.checked_mul(k).ok_or(ErrorKind::MultiplyOverflow)?;
+        k += D1;
+    };
+    Ok(acc)
+}
+
+//##########################################################################################################################
+
+/// Γ(x) = (x - 1)! for positive integers
+/// Γ(x) = Γ(x + n) / (x * (x + 1) * ... * (x + n - 1)) for x < 0
+/// Γ(x) = π / (sin(πx) * Γ(1 - x)) for x < -20
+/// Γ(x) = ±e^(ln(π) - ln|sin(πx)| - ln(Γ(1 - x))) once Γ(1 - x) exceeds Decimal::MAX, zero below the scale of a Decimal
+pub fn d_gamma(
+    value: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    let ctx = |e: Error| e.context("d_gamma", value);
+    if value.fract().is_zero() {
+        if value <= D0 { Err(ctx(ErrorKind::Pole.into()))? };
+        if value > GAMMA_INT_UPPER_BD { Err(ctx(ErrorKind::MultiplyOverflow.into()))? };
+        return d_fac(value.to_usize().ok_or(ctx(ErrorKind::OptionInvalid.into()))? - 1).map_err(ctx)
+    };
+    if value < REFLECT_UPPER_BD {
+        let sin = sin_pi(value, terms).map_err(ctx)?;
+        let gamma = match gamma_positive(D1 - value, terms) {
+            Err(err) if err.kind() == ErrorKind::MultiplyOverflow => {
+                let ln = LN_PI - d_ln(sin.abs(), terms).map_err(ctx)? - lgamma_positive(D1 - value, terms).map_err(ctx)?;
+                return Ok(sin.signum() * d_exp(ln, terms).map_err(ctx)?)
+            },
+            res => res.map_err(ctx)?,
+        };
+        let den = sin.checked_mul(gamma).ok_or(ctx(ErrorKind::MultiplyOverflow.into()))?;
+        PI.checked_div(den).ok_or(ctx(ErrorKind::MultiplyOverflow.into()))
+    }
+    else if value < D0 {
+        let (shifted, prod) = shift_to_unit(value);
+        let gamma = gamma_positive(shifted, terms).map_err(ctx)?;
+        gamma.checked_div(prod).ok_or(ctx(ErrorKind::MultiplyOverflow.into()))
+    }
+    else { gamma_positive(value, terms).map_err(ctx) }
+}
+
+/// ln|Γ(x)| = ln((x - 1)!) for positive integers
+/// ln|Γ(x)| = ln(Γ(x + n)) - ln|x * (x + 1) * ... * (x + n - 1)| for x < 0
+/// ln|Γ(x)| = ln(π) - ln|sin(πx)| - ln(Γ(1 - x)) for x < -20
+pub fn d_lgamma(
+    value: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    let ctx = |e: Error| e.context("d_lgamma", value);
+    if value.fract().is_zero() && (value <= GAMMA_INT_UPPER_BD) {
+        return d_gamma(value, terms).and_then(|g| d_ln(g, terms)).map_err(ctx)
+    };
+    if value < REFLECT_UPPER_BD {
+        let sin = sin_pi(value, terms).map_err(ctx)?;
+        let ln_sin = d_ln(sin.abs(), terms).map_err(ctx)?;
+        Ok(LN_PI - ln_sin - lgamma_positive(D1 - value, terms).map_err(ctx)?)
+    }
+    else if value < D0 {
+        let (shifted, prod) = shift_to_unit(value);
+        let ln_prod = d_ln(prod.abs(), terms).map_err(ctx)?;
+        Ok(lgamma_positive(shifted, terms).map_err(ctx)? - ln_prod)
+    }
+    else { lgamma_positive(value, terms).map_err(ctx) }
+}
+
+/// 1/Γ(x), with 1/Γ(x) = 0 at the poles of Γ(x)
+/// 1/Γ(x) = sin(πx) * Γ(1 - x) / π for x < -20
+/// 1/Γ(x) = 0 once Γ(x) exceeds Decimal::MAX, below the scale of a Decimal
+pub fn d_rgamma(
+    value: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    let ctx = |e: Error| e.context("d_rgamma", value);
+    if value.fract().is_zero() && (value <= D0) { return Ok(D0) };
+    if value < REFLECT_UPPER_BD {
+        let gamma = gamma_positive(D1 - value, terms).map_err(ctx)?;
+        let sin = sin_pi(value, terms).map_err(ctx)?;
+        Ok(sin.checked_mul(gamma).ok_or(ctx(ErrorKind::MultiplyOverflow.into()))? / PI)
+    }
+    else if value < D0 {
+        let (shifted, prod) = shift_to_unit(value);
+        let gamma = gamma_positive(shifted, terms).map_err(ctx)?;
+        Ok(prod / gamma)
+    }
+    else {
+        match d_gamma(value, terms) {
+            Err(err) if err.kind() == ErrorKind::MultiplyOverflow => Ok(D0),
+            res => Ok(D1 / res.map_err(ctx)?),
+        }
+    }
+}
+
+//##########################################################################################################################
+
+/// sum(n=0; x^n / (s * (s + 1) * ... * (s + n))), summed to the precision of a Decimal
+#[inline]
+fn gamma_inc_series(
+    value: Decimal,
+    arg: Decimal
+) -> Result<Decimal, Error> {
+    // Set Variables
+    let mut term = D1 / value;
+    let mut sum = term;
+    let mut k = value;
+    for _ in 0..INC_ITER_UPPER_BD {
+        k += D1;
+        term = term.checked_mul(arg / k).ok_or(ErrorKind::MultiplyOverflow)?;
+        sum = sum.checked_add(term).ok_or(ErrorKind::AddOverflow)?;
+        if term <= (sum * INC_TOLERANCE) { return Ok(sum) };
+    };
+    Err(ErrorKind::NotConverged.into())
+}
+
+/// 1 / (x + 1 - s - 1 * (1 - s) / (x + 3 - s - 2 * (2 - s) / (x + 5 - s - ...))), by Lentz's method
+#[inline]
+fn gamma_inc_fraction(
+    value: Decimal,
+    arg: Decimal
+) -> Result<Decimal, Error> {
+    let tiny = |v: Decimal| if v.abs() < INC_TINY {INC_TINY} else {v};
+    // Set Variables
+    let mut b = arg + D1 - value;
+    let mut c = D1 / INC_TINY;
+    let mut d = D1 / tiny(b);
+    let mut h = d;
+    for i in 1..INC_ITER_UPPER_BD {
+        let n = Decimal::from(i);
+        let a = -n * (n - value);
+        b += D2;
+        d = D1 / tiny(a.checked_mul(d).ok_or(ErrorKind::MultiplyOverflow)? + b);
+        c = tiny(b + (a / c));
+        let delta = d * c;
+        h = h.checked_mul(delta).ok_or(ErrorKind::MultiplyOverflow)?;
+        if (delta - D1).abs() < INC_TOLERANCE { return Ok(h) };
+    };
+    Err(ErrorKind::NotConverged.into())
+}
+
+/// x^s * e^(-x) / e^k, with underflow taken as zero
+#[inline]
+fn gamma_inc_prefix(
+    value: Decimal,
+    arg: Decimal,
+    shift: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    let ln = value.checked_mul(d_ln(arg, terms)?).ok_or(ErrorKind::MultiplyOverflow)?;
+    d_exp(ln - arg - shift, terms)
+}
+
+/// Series part and fraction part of the incomplete gamma functions
+/// Returns (γ(s, x) / k, true) by the series for x < s + 1, and (Γ(s, x) / k, false) by the fraction otherwise
+#[inline]
+fn gamma_inc_helper(
+    value: Decimal,
+    arg: Decimal,
+    shift: Decimal,
+    terms: usize
+) -> Result<(Decimal, bool), Error> {
+    if (value <= D0) || (arg < D0) || (value > INC_UPPER_BD) || (arg > INC_UPPER_BD) { Err(ErrorKind::InputOutOfRange)? };
+    let prefix = gamma_inc_prefix(value, arg, shift, terms)?;
+    if arg < (value + D1) {
+        let sum = gamma_inc_series(value, arg)?;
+        Ok((prefix.checked_mul(sum).ok_or(ErrorKind::MultiplyOverflow)?, true))
+    }
+    else {
+        let frac = gamma_inc_fraction(value, arg)?;
+        Ok((prefix.checked_mul(frac).ok_or(ErrorKind::MultiplyOverflow)?, false))
+    }
+}
+
+//##########################################################################################################################
+
+/// γ(s, x) = x^s * e^(-x) * sum(n=0; x^n / (s * (s + 1) * ... * (s + n))) for x < s + 1
+/// γ(s, x) = Γ(s) - Γ(s, x) otherwise
+pub fn d_gamma_lower(
+    value: Decimal,
+    arg: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    let ctx = |e: Error| e.context("d_gamma_lower", format!("{}, {}", value, arg));
+    if (value > D0) && arg.is_zero() { return Ok(D0) };
+    let (res, lower) = gamma_inc_helper(value, arg, D0, terms).map_err(ctx)?;
+    if lower { Ok(res) } else { Ok(d_gamma(value, terms).map_err(ctx)? - res) }
+}
+
+/// Γ(s, x) = x^s * e^(-x) / (x + 1 - s - 1 * (1 - s) / (x + 3 - s - ...)) for x >= s + 1
+/// Γ(s, x) = Γ(s) - γ(s, x) otherwise
+pub fn d_gamma_upper(
+    value: Decimal,
+    arg: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    let ctx = |e: Error| e.context("d_gamma_upper", format!("{}, {}", value, arg));
+    if (value > D0) && arg.is_zero() { return d_gamma(value, terms).map_err(ctx) };
+    let (res, lower) = gamma_inc_helper(value, arg, D0, terms).map_err(ctx)?;
+    if lower { Ok(d_gamma(value, terms).map_err(ctx)? - res) } else { Ok(res) }
+}
+
+/// P(s, x) = γ(s, x) / Γ(s)
+pub fn d_gamma_p(
+    value: Decimal,
+    arg: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    let ctx = |e: Error| e.context("d_gamma_p", format!("{}, {}", value, arg));
+    if (value > D0) && arg.is_zero() { return Ok(D0) };
+    let shift = d_lgamma(value, terms).map_err(ctx)?;
+    let (res, lower) = gamma_inc_helper(value, arg, shift, terms).map_err(ctx)?;
+    Ok(if lower {res} else {D1 - res})
+}
+
+/// Q(s, x) = Γ(s, x) / Γ(s) = 1 - P(s, x)
+pub fn d_gamma_q(
+    value: Decimal,
+    arg: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    let ctx = |e: Error| e.context("d_gamma_q", format!("{}, {}", value, arg));
+    if (value > D0) && arg.is_zero() { return Ok(D1) };
+    let shift = d_lgamma(value, terms).map_err(ctx)?;
+    let (res, lower) = gamma_inc_helper(value, arg, shift, terms).map_err(ctx)?;
+    Ok(if lower {D1 - res} else {res})
+}
+
+//##########################################################################################################################
@@ -0,0 +1,165 @@
+
+// Imports
+use rust_decimal::prelude::*;
+use rust_decimal_macros::dec;
+
+// Modules
+use crate::constants::{ E, D1DIVE };
+use crate::error::{ Error, ErrorKind };
+
+use crate::sqrt::{ d_sqrt };
+use crate::euler::{ d_exp, d_ln };
+
+//##########################################################################################################################
+
+// Constants
+const D0: Decimal = Decimal::ZERO;
+const D1: Decimal = Decimal::ONE;
+const D2: Decimal = Decimal::TWO;
+const D3: Decimal = dec!(3);
+const D3DIV2: Decimal = dec!(1.5);
+pub(crate) const D11DIV72: Decimal = dec!(0.1527777777777777777777777778);
+
+// Below this bound W(x) is seeded by the series around the branch point -1/e
+const BRANCH_UPPER_BD: Decimal = dec!(-0.25);
+
+// Bound of the Maclaurin series seed of W_0(x)
+const SERIES_UPPER_BD: Decimal = dec!(0.25);
+
+// Largest number of Halley steps
+pub(crate) const HALLEY_ITER_UPPER_BD: usize = 64;
+
+// Step size where Halley's method stops
+pub(crate) const TOLERANCE: Decimal = dec!(0.000000000000000000000000001);
+
+//##########################################################################################################################
+
+/// W(x) = -1 + p - p^2/3 + 11/72 * p^3, with p = ±sqrt(2 * (e * x + 1)) for x near -1/e
+#[inline]
+fn seed_branch(
+    value: Decimal,
+    sign: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    // e * x + 1 rounds below zero just above -1/e
+    let p = sign * d_sqrt((D2 * ((E * value) + D1)).max(D0), terms)?;
+    Ok(-D1 + p - (p * p / D3) + (D11DIV72 * p * p * p))
+}
+
+/// W(x) = L1 - L2 + L2/L1, with L1 = ln|x| and L2 = ln|L1|, for large |W(x)|
+#[inline]
+fn seed_asymptotic(
+    value: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    let l1 = d_ln(value.abs(), terms)?;
+    let l2 = d_ln(l1.abs(), terms)?;
+    Ok(l1 - l2 + (l2 / l1))
+}
+
+/// Halley's method from a seed, stopping at the precision of the steps
+#[inline]
+fn halley<F>(
+    seed: Decimal,
+    mut step: F
+) -> Result<Decimal, Error>
+where F: FnMut(Decimal) -> Result<Decimal, Error> {
+    // Set Variables
+    let mut res = seed;
+    let mut last = Decimal::MAX;
+    for _ in 0..HALLEY_ITER_UPPER_BD {
+        let delta = step(res)?;
+        // Steps stop shrinking at the precision of e^w and ln(w)
+        if delta.abs() >= last { return Ok(res) };
+        res -= delta;
+        last = delta.abs();
+        if last < TOLERANCE { return Ok(res) };
+    };
+    Err(ErrorKind::NotConverged.into())
+}
+
+/// f(w) = w * e^w - x
+/// w' = w - f / (e^w * (w + 1) - (w + 2) * f / (2w + 2))
+#[inline]
+fn halley_direct(
+    value: Decimal,
+    seed: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    halley(seed, |w| {
+        let exp = d_exp(w, terms)?;
+        let f = (w * exp) - value;
+        let corr = ((w + D2) * f).checked_div(D2 * (w + D1)).ok_or(ErrorKind::DivisionByZero)?;
+        f.checked_div((exp * (w + D1)) - corr).ok_or(ErrorKind::DivisionByZero.into())
+    })
+}
+
+/// g(w) = w + ln|w| - ln|x|, with g' = 1 + 1/w and g'' = -1/w^2
+/// w' = w - 2 * g * g' / (2 * g'^2 - g * g'')
+#[inline]
+fn halley_log(
+    value: Decimal,
+    seed: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    let ln_x = d_ln(value.abs(), terms)?;
+    halley(seed, |w| {
+        let g = w + d_ln(w.abs(), terms)? - ln_x;
+        let inv = D1 / w;
+        let g1 = D1 + inv;
+        let g2 = -(inv * inv);
+        (D2 * g * g1).checked_div((D2 * g1 * g1) - (g * g2)).ok_or(ErrorKind::DivisionByZero.into())
+    })
+}
+
+//##########################################################################################################################
+
+/// W_0(x), the solution of w * e^w = x with w >= -1, for x >= -1/e
+pub fn d_lambert_w0(
+    value: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    let ctx = |e: Error| e.context("d_lambert_w0", value);
+    if value < -D1DIVE { Err(ctx(ErrorKind::InputOutOfRange.into()))? };
+         if value == -D1DIVE { Ok(-D1) }
+    else if value == D0      { Ok(D0) }
+    else if value == E       { Ok(D1) }
+    else if value < BRANCH_UPPER_BD {
+        let seed = seed_branch(value, D1, terms).map_err(ctx)?;
+        halley_direct(value, seed, terms).map_err(ctx)
+    }
+    else if value <= SERIES_UPPER_BD {
+        // W_0(x) = x - x^2 + 3/2 * x^3 - ...
+        let seed = value - (value * value) + (D3DIV2 * value * value * value);
+        halley_direct(value, seed, terms).map_err(ctx)
+    }
+    else if value <= E {
+        let seed = d_ln(D1 + value, terms).map_err(ctx)?;
+        halley_direct(value, seed, terms).map_err(ctx)
+    }
+    else {
+        let seed = seed_asymptotic(value, terms).map_err(ctx)?;
+        halley_log(value, seed, terms).map_err(ctx)
+    }
+}
+
+/// W_-1(x), the solution of w * e^w = x with w <= -1, for -1/e <= x < 0
+pub fn d_lambert_wm1(
+    value: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    let ctx = |e: Error| e.context("d_lambert_wm1", value);
+    if (value < -D1DIVE) || (value > D0) { Err(ctx(ErrorKind::InputOutOfRange.into()))? };
+    if value == D0 { Err(ctx(ErrorKind::Pole.into()))? };
+    if value == -D1DIVE { return Ok(-D1) };
+    if value < BRANCH_UPPER_BD {
+        let seed = seed_branch(value, -D1, terms).map_err(ctx)?;
+        halley_direct(value, seed, terms).map_err(ctx)
+    }
+    else {
+        let seed = seed_asymptotic(value, terms).map_err(ctx)?;
+        halley_log(value, seed, terms).map_err(ctx)
+    }
+}
+
+//##########################################################################################################################
@@ -0,0 +1,9 @@
+pub mod gamma;
+pub mod beta;
+pub mod erf;
+pub mod lambert;
+pub mod bessel;
+pub mod expint;
+
+#[cfg(test)]
+mod tests;
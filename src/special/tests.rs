@@ -0,0 +1,553 @@
+
+// Imports
+use rust_decimal::prelude::*;
+use rust_decimal_macros::dec;
+
+// Modules
+use crate::error::{ Error, ErrorKind };
+
+use crate::constants::{ E, D1DIVE };
+
+use crate::basic::{ dec };
+use crate::factorial::{ d_fac };
+use crate::euler::{ d_exp };
+use crate::special::gamma::{ d_gamma, d_lgamma, d_rgamma, d_gamma_lower, d_gamma_upper, d_gamma_p, d_gamma_q };
+use crate::special::beta::{ d_beta, d_beta_inc };
+use crate::special::erf::{ d_erf, d_erfc, d_erfcx, d_erfinv, d_erf_dp, d_erfinv_dp };
+use crate::special::lambert::{ d_lambert_w0, d_lambert_wm1 };
+use crate::special::bessel::{ d_bessel_j, d_bessel_y, d_bessel_i, d_bessel_k };
+use crate::special::expint::{ d_ei, d_e1, d_li, d_Li };
+
+//##########################################################################################################################
+
+// Constants
+const D0: Decimal = Decimal::ZERO;
+const D1: Decimal = Decimal::ONE;
+const D1DIV2: Decimal = dec!(0.5);
+
+const SQRT_PI: Decimal = dec!(1.7724538509055160272981674833);
+
+const GAMMA_N3DIV2: Decimal = dec!(2.3632718012073547030642233111);
+const GAMMA_10_3: Decimal = dec!(716430.68906237524454762965472);
+const GAMMA_N7_25: Decimal = dec!(0.0005303977063521478618522107);
+const LGAMMA_100_5: Decimal = dec!(361.43554046777762155525191270);
+const LGAMMA_N5DIV2: Decimal = dec!(-0.0562437164976740506725945301);
+const RGAMMA_1DIV4: Decimal = dec!(0.2758156628302093143599455400);
+
+const PI: Decimal = dec!(3.1415926535897932384626433833);
+const BETA_30_40: Decimal = dec!(0.0000000000000000000010539425);
+const BETA_5DIV2_7DIV2: Decimal = dec!(0.0368155389092553895132341021);
+const BETA_81DIV2_1DIV2: Decimal = dec!(0.2793751706554306475395958363);
+
+const GAMMA_5DIV2: Decimal = dec!(1.3293403881791370204736256125);
+const GAMMA_LOWER_5DIV2_3DIV2: Decimal = dec!(0.3988209453923446296063842532);
+const GAMMA_UPPER_5DIV2_3DIV2: Decimal = dec!(0.9305194427867923908672413593);
+const GAMMA_UPPER_1DIV2_30: Decimal = dec!(0.0000000000000168130320865290);
+const GAMMA_P_100_90: Decimal = dec!(0.1582209891864301681049696997);
+const GAMMA_Q_1000_1100: Decimal = dec!(0.0010593232539299773488749334);
+
+const BETA_INC_1DIV2_1DIV2_3DIV10: Decimal = dec!(0.3690101195655453827554305588);
+const BETA_INC_50_60_9DIV20: Decimal = dec!(0.4642352914306035358503014107);
+const BETA_INC_11DIV2_5DIV4_9DIV10: Decimal = dec!(0.6656258892641173356577560232);
+
+const ERF_1DIV2: Decimal = dec!(0.5204998778130465376827466539);
+const ERFC_3: Decimal = dec!(0.0000220904969985854413727761);
+const ERFC_5: Decimal = dec!(0.0000000000015374597944280349);
+const ERFC_15DIV2: Decimal = dec!(0.0000000000000000000000000278);
+const ERFCX_2: Decimal = dec!(0.2553956763105057438650885809);
+const ERFCX_20: Decimal = dec!(0.0281743487410513193186491545);
+const ERFCX_N2: Decimal = dec!(108.94090438997797241235543383);
+const ERFINV_1DIV2: Decimal = dec!(0.4769362762044698733814183536);
+const ERFINV_1_1E_N20: Decimal = dec!(6.6015806223551425615163916324);
+const ERFINV_N3DIV10: Decimal = dec!(-0.2724627147267543556219575986);
+
+const OMEGA: Decimal = dec!(0.5671432904097838729999686622);
+const LAMBERT_W0_10: Decimal = dec!(1.7455280027406993830743012649);
+const LAMBERT_W0_1E6: Decimal = dec!(11.383358086140052622000156782);
+const LAMBERT_W0_N3DIV10: Decimal = dec!(-0.4894022271802149690362312520);
+const LAMBERT_W0_N3678: Decimal = dec!(-0.9793607149578284774761844435);
+const LAMBERT_WM1_N3DIV10: Decimal = dec!(-1.7813370234216276119741702815);
+const LAMBERT_WM1_N1E_N22: Decimal = dec!(-54.657967032715590297488735138);
+
+const BESSEL_J0_1: Decimal = dec!(0.7651976865579665514497175261);
+const BESSEL_J3_7: Decimal = dec!(-0.1675555879953342360315111126);
+const BESSEL_J1_10: Decimal = dec!(0.0434727461688614366697487680);
+const BESSEL_J5_20: Decimal = dec!(0.1511697679823949746071004557);
+const BESSEL_J0_50: Decimal = dec!(0.0558123276692518150047504785);
+const BESSEL_JN27DIV10_123DIV10: Decimal = dec!(-0.2174712593370182139158779220);
+const BESSEL_Y0_1: Decimal = dec!(0.0882569642156769579829267660);
+const BESSEL_Y1_10: Decimal = dec!(0.2490154242069538839232834747);
+const BESSEL_Y3_40: Decimal = dec!(-0.0068291034133842081488273141);
+const BESSEL_Y3DIV10_5: Decimal = dec!(-0.1970568791161449432807647198);
+const BESSEL_I0_1: Decimal = dec!(1.2660658777520083355982446252);
+const BESSEL_I1_10: Decimal = dec!(2670.9883037012546543410319668);
+const BESSEL_I5DIV2_3: Decimal = dec!(1.5153394466819651377405786527);
+const BESSEL_K0_1: Decimal = dec!(0.4210244382407083333356273792);
+const BESSEL_K1_5: Decimal = dec!(0.0040446134454521642083650218);
+const BESSEL_K2_30: Decimal = dec!(0.0000000000000227699296325583);
+const BESSEL_K999999DIV1000000_1: Decimal = dec!(0.6019068091731366713199202200);
+const BESSEL_K20000001DIV10000000_1: Decimal = dec!(1.6248391032215279366931501377);
+const BESSEL_K3DIV10_1DIV10: Decimal = dec!(2.8050564750215723106588528045);
+
+const EI_1: Decimal = dec!(1.8951178163559367554665209343);
+const EI_1DIV2: Decimal = dec!(0.4542199048631735799205238127);
+const EI_10: Decimal = dec!(2492.2289762418777591384401440);
+const EI_50: Decimal = dec!(105856368971316909630.61541433);
+const EI_68: Decimal = dec!(5082177148694185307112975717.5);
+const EI_N5DIV2: Decimal = dec!(-0.0249149178702697354956280123);
+const E1_1DIV10: Decimal = dec!(1.8229239584193906660809136583);
+const E1_5: Decimal = dec!(0.0011482955912753257973305620);
+const E1_30: Decimal = dec!(0.0000000000000030215520106888);
+const LI_10: Decimal = dec!(6.1655995047872979375229817527);
+const LI_1DIV2: Decimal = dec!(-0.3786710430610879767272071846);
+const LI_1E6: Decimal = dec!(78627.549159462181919862910748);
+const LI_1E20: Decimal = dec!(2220819602783663483.5483055321);
+const LI_OFFSET_1000: Decimal = dec!(176.56449421003473390279603506);
+
+//##########################################################################################################################
+
+// Iteration Terms
+const TEST_ITER: usize = 32;
+
+// Decimal Precision
+const TEST_DIG: u32 = 24;
+
+//##########################################################################################################################
+
+#[test]
+fn gamma() -> Result<(), Error> {
+    // Γ(n) == (n - 1)! for n in 1..=28
+    for n in 1..=28 {
+        assert_eq!(d_gamma(dec(n), TEST_ITER)?, d_fac(n - 1)?);
+    };
+    // Γ(1/2) == sqrt(π)
+    let res1 = d_gamma(D1DIV2, TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res1, SQRT_PI.round_dp(TEST_DIG));
+    // Γ(-3/2) == 4 * sqrt(π) / 3
+    let res2 = d_gamma(dec!(-1.5), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res2, GAMMA_N3DIV2.round_dp(TEST_DIG));
+    // Γ(10.3) == Γ(10.3)
+    let res3 = d_gamma(dec!(10.3), TEST_ITER)?.round_dp(20);
+    assert_eq!(res3, GAMMA_10_3.round_dp(20));
+    // Γ(-7.25) == Γ(-7.25)
+    let res4 = d_gamma(dec!(-7.25), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res4, GAMMA_N7_25.round_dp(TEST_DIG));
+    // ln(Γ(100.5)) == ln(Γ(100.5))
+    let res5 = d_lgamma(dec!(100.5), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res5, LGAMMA_100_5.round_dp(TEST_DIG));
+    // ln|Γ(-5/2)| == ln|Γ(-5/2)|
+    let res6 = d_lgamma(dec!(-2.5), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res6, LGAMMA_N5DIV2.round_dp(TEST_DIG));
+    // 1/Γ(1/4) == 1/Γ(1/4)
+    let res7 = d_rgamma(dec!(0.25), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res7, RGAMMA_1DIV4.round_dp(TEST_DIG));
+    // 1/Γ(-3) == 0
+    let res8 = d_rgamma(dec!(-3), TEST_ITER)?;
+    assert_eq!(res8, D0);
+    // Γ(0) == Pole, Γ(-2) == Pole
+    let res9 = d_gamma(D0, TEST_ITER).map_err(|e| e.kind());
+    assert_eq!(res9, Err(ErrorKind::Pole));
+    let res10 = d_lgamma(dec!(-2), TEST_ITER).map_err(|e| e.kind());
+    assert_eq!(res10, Err(ErrorKind::Pole));
+    // Γ(30) == MultiplyOverflow
+    let res11 = d_gamma(dec!(30), TEST_ITER).map_err(|e| e.kind());
+    assert_eq!(res11, Err(ErrorKind::MultiplyOverflow));
+    // Γ(-40.5) == 0 and 1/Γ(30) == 0, below the scale of a Decimal
+    let res12 = d_gamma(dec!(-40.5), TEST_ITER)?;
+    assert_eq!(res12, D0);
+    let res13 = d_rgamma(dec!(30), TEST_ITER)?;
+    assert_eq!(res13, D0);
+    // Γ(-30 + 10^-20) == Γ(-30 + 10^-20), near the pole past Γ(31) > Decimal::MAX
+    let res14 = d_gamma(dec!(-29.99999999999999999999), TEST_ITER)?.round_dp(20);
+    assert_eq!(res14, dec!(0.00000000000037699876));
+    // Return Ok
+    Ok(())
+}
+
+//##########################################################################################################################
+
+#[test]
+fn beta() -> Result<(), Error> {
+    // B(2, 3) == 1/12
+    let res1 = d_beta(dec!(2), dec!(3), TEST_ITER)?;
+    assert_eq!(res1, D1 / dec!(12));
+    // B(30, 40) == 1 / (69 * C(68, 29))
+    let res2 = d_beta(dec!(30), dec!(40), TEST_ITER)?;
+    assert_eq!(res2, BETA_30_40);
+    // B(1/2, 1/2) == π
+    let res3 = d_beta(D1DIV2, D1DIV2, TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res3, PI.round_dp(TEST_DIG));
+    // B(5/2, 7/2) == B(5/2, 7/2)
+    let res4 = d_beta(dec!(2.5), dec!(3.5), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res4, BETA_5DIV2_7DIV2.round_dp(TEST_DIG));
+    // B(-3/2, 3) == 16/3
+    let res5 = d_beta(dec!(-1.5), dec!(3), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res5, (dec!(16) / dec!(3)).round_dp(TEST_DIG));
+    // B(81/2, 1/2) == B(81/2, 1/2)
+    let res6 = d_beta(dec!(40.5), D1DIV2, TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res6, BETA_81DIV2_1DIV2.round_dp(TEST_DIG));
+    // B(-1/2, -1/2) == 0
+    let res7 = d_beta(-D1DIV2, -D1DIV2, TEST_ITER)?;
+    assert_eq!(res7, D0);
+    // B(0, 1) == Pole
+    let res8 = d_beta(D0, D1, TEST_ITER).map_err(|e| e.kind());
+    assert_eq!(res8, Err(ErrorKind::Pole));
+    // B(100, 120) == 0, below the scale of a Decimal, by m_fac past u128
+    let res9 = d_beta(dec!(100), dec!(120), TEST_ITER)?;
+    assert_eq!(res9, D0);
+    // B(20, 25) == 1 / (44 * C(43, 19)), to the last digit
+    let res10 = d_beta(dec!(20), dec!(25), TEST_ITER)?;
+    assert_eq!(res10, dec!(0.0000000000000283923241113338));
+    // B(201/2, 120) == B(100, 120), through d_exp rather than the binomial
+    let res11 = d_beta(dec!(100.5), dec!(120), TEST_ITER)?;
+    assert_eq!(res11, res9);
+    // Return Ok
+    Ok(())
+}
+
+//##########################################################################################################################
+
+#[test]
+fn incomplete() -> Result<(), Error> {
+    // γ(5/2, 3/2) == γ(5/2, 3/2)
+    let res1 = d_gamma_lower(dec!(2.5), dec!(1.5), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res1, GAMMA_LOWER_5DIV2_3DIV2.round_dp(TEST_DIG));
+    // Γ(5/2, 3/2) == Γ(5/2, 3/2)
+    let res2 = d_gamma_upper(dec!(2.5), dec!(1.5), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res2, GAMMA_UPPER_5DIV2_3DIV2.round_dp(TEST_DIG));
+    // γ(5/2, 7) + Γ(5/2, 7) == Γ(5/2)
+    let res3 = (d_gamma_lower(dec!(2.5), dec!(7), TEST_ITER)? + d_gamma_upper(dec!(2.5), dec!(7), TEST_ITER)?).round_dp(TEST_DIG);
+    assert_eq!(res3, GAMMA_5DIV2.round_dp(TEST_DIG));
+    // Γ(1/2, 30) == Γ(1/2, 30)
+    let res4 = d_gamma_upper(D1DIV2, dec!(30), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res4, GAMMA_UPPER_1DIV2_30.round_dp(TEST_DIG));
+    // P(1, 5/2) == 1 - e^(-5/2)
+    let res5 = d_gamma_p(D1, dec!(2.5), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res5, (D1 - d_exp(dec!(-2.5), TEST_ITER)?).round_dp(TEST_DIG));
+    // P(100, 90) == P(100, 90)
+    let res6 = d_gamma_p(dec!(100), dec!(90), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res6, GAMMA_P_100_90.round_dp(TEST_DIG));
+    // Q(1000, 1100) == Q(1000, 1100)
+    let res7 = d_gamma_q(dec!(1000), dec!(1100), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res7, GAMMA_Q_1000_1100.round_dp(TEST_DIG));
+    // Q(3, 500) == 0
+    let res8 = d_gamma_q(dec!(3), dec!(500), TEST_ITER)?;
+    assert_eq!(res8, D0);
+    // I_(2/5)(2, 3) == 328/625
+    let res9 = d_beta_inc(dec!(2), dec!(3), dec!(0.4), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res9, dec!(0.5248));
+    // I_(3/10)(1/2, 1/2) == 2 * asin(sqrt(3/10)) / π
+    let res10 = d_beta_inc(D1DIV2, D1DIV2, dec!(0.3), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res10, BETA_INC_1DIV2_1DIV2_3DIV10.round_dp(TEST_DIG));
+    // I_(9/20)(50, 60) == I_(9/20)(50, 60)
+    let res11 = d_beta_inc(dec!(50), dec!(60), dec!(0.45), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res11, BETA_INC_50_60_9DIV20.round_dp(TEST_DIG));
+    // I_(9/10)(11/2, 5/4) == 1 - I_(1/10)(5/4, 11/2)
+    let res12 = d_beta_inc(dec!(5.5), dec!(1.25), dec!(0.9), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res12, BETA_INC_11DIV2_5DIV4_9DIV10.round_dp(TEST_DIG));
+    let res13 = (D1 - d_beta_inc(dec!(1.25), dec!(5.5), dec!(0.1), TEST_ITER)?).round_dp(TEST_DIG);
+    assert_eq!(res13, res12);
+    // γ(0, 1) == InputOutOfRange, I_2(1, 1) == InputOutOfRange
+    let res14 = d_gamma_lower(D0, D1, TEST_ITER).map_err(|e| e.kind());
+    assert_eq!(res14, Err(ErrorKind::InputOutOfRange));
+    let res15 = d_beta_inc(D1, D1, dec!(2), TEST_ITER).map_err(|e| e.kind());
+    assert_eq!(res15, Err(ErrorKind::InputOutOfRange));
+    // Return Ok
+    Ok(())
+}
+
+//##########################################################################################################################
+
+#[test]
+fn erf() -> Result<(), Error> {
+    // erf(1/2) == erf(1/2)
+    let res1 = d_erf(D1DIV2, TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res1, ERF_1DIV2.round_dp(TEST_DIG));
+    // erf(-1/2) == -erf(1/2)
+    let res2 = d_erf(-D1DIV2, TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res2, -res1);
+    // erf(1/2) to 20 digits
+    let (res3, _) = d_erf_dp(D1DIV2, 20)?;
+    assert_eq!(res3.round_dp(20), ERF_1DIV2.round_dp(20));
+    // erfc(3) == erfc(3), erfc(5) == erfc(5)
+    let res4 = d_erfc(dec!(3), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res4, ERFC_3.round_dp(TEST_DIG));
+    let res5 = d_erfc(dec!(5), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res5, ERFC_5.round_dp(TEST_DIG));
+    // erfc(15/2) keeps its digits down to the scale of a Decimal
+    let res6 = d_erfc(dec!(7.5), TEST_ITER)?;
+    assert_eq!(res6, ERFC_15DIV2);
+    // erfc(41/5) == 0, below the scale of a Decimal
+    let res7 = d_erfc(dec!(8.2), TEST_ITER)?;
+    assert_eq!(res7, D0);
+    // erfc(-3) == 2 - erfc(3)
+    let res8 = d_erfc(dec!(-3), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res8, (dec!(2) - ERFC_3).round_dp(TEST_DIG));
+    // erfcx(2) == erfcx(2), erfcx(20) == erfcx(20), erfcx(-2) == erfcx(-2)
+    let res9 = d_erfcx(dec!(2), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res9, ERFCX_2.round_dp(TEST_DIG));
+    let res10 = d_erfcx(dec!(20), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res10, ERFCX_20.round_dp(TEST_DIG));
+    let res11 = d_erfcx(dec!(-2), TEST_ITER)?.round_dp(20);
+    assert_eq!(res11, ERFCX_N2.round_dp(20));
+    // erfinv(1/2) == erfinv(1/2), erfinv(-3/10) == erfinv(-3/10)
+    let res12 = d_erfinv(D1DIV2, TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res12, ERFINV_1DIV2.round_dp(TEST_DIG));
+    let res13 = d_erfinv(dec!(-0.3), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res13, ERFINV_N3DIV10.round_dp(TEST_DIG));
+    // erfinv(1 - 10^-20) == erfinv(1 - 10^-20)
+    let (res14, _) = d_erfinv_dp(dec!(0.99999999999999999999), 28)?;
+    assert_eq!(res14.round_dp(TEST_DIG), ERFINV_1_1E_N20.round_dp(TEST_DIG));
+    // erf(erfinv(9/10)) == 9/10
+    let res15 = d_erf(d_erfinv(dec!(0.9), TEST_ITER)?, TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res15, dec!(0.9));
+    // erfinv(1) == Pole, erfinv(3/2) == InputOutOfRange
+    let res16 = d_erfinv(D1, TEST_ITER).map_err(|e| e.kind());
+    assert_eq!(res16, Err(ErrorKind::Pole));
+    let res17 = d_erfinv(dec!(1.5), TEST_ITER).map_err(|e| e.kind());
+    assert_eq!(res17, Err(ErrorKind::InputOutOfRange));
+    // Return Ok
+    Ok(())
+}
+
+//##########################################################################################################################
+
+#[test]
+fn lambert() -> Result<(), Error> {
+    // W_0(-1/e) == -1, W_-1(-1/e) == -1
+    let res1 = d_lambert_w0(-D1DIVE, TEST_ITER)?;
+    assert_eq!(res1, -D1);
+    let res2 = d_lambert_wm1(-D1DIVE, TEST_ITER)?;
+    assert_eq!(res2, -D1);
+    // W_0(1) == Ω, W_0(e) == 1
+    let res3 = d_lambert_w0(D1, TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res3, OMEGA.round_dp(TEST_DIG));
+    let res4 = d_lambert_w0(E, TEST_ITER)?;
+    assert_eq!(res4, D1);
+    // W_0(10) == W_0(10), W_0(10^6) == W_0(10^6)
+    let res5 = d_lambert_w0(dec!(10), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res5, LAMBERT_W0_10.round_dp(TEST_DIG));
+    let res6 = d_lambert_w0(dec!(1_000_000), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res6, LAMBERT_W0_1E6.round_dp(TEST_DIG));
+    // W_0(-3/10) == W_0(-3/10), W_-1(-3/10) == W_-1(-3/10)
+    let res7 = d_lambert_w0(dec!(-0.3), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res7, LAMBERT_W0_N3DIV10.round_dp(TEST_DIG));
+    let res8 = d_lambert_wm1(dec!(-0.3), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res8, LAMBERT_WM1_N3DIV10.round_dp(TEST_DIG));
+    // W_0(-0.3678) == W_0(-0.3678), near the branch point
+    let res9 = d_lambert_w0(dec!(-0.3678), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res9, LAMBERT_W0_N3678.round_dp(TEST_DIG));
+    // W_-1(-10^-22) == W_-1(-10^-22)
+    let res10 = d_lambert_wm1(dec!(-0.0000000000000000000001), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res10, LAMBERT_WM1_N1E_N22.round_dp(TEST_DIG));
+    // W_0(5/2) * e^(W_0(5/2)) == 5/2
+    let res11 = d_lambert_w0(dec!(2.5), TEST_ITER)?;
+    assert_eq!((res11 * d_exp(res11, TEST_ITER)?).round_dp(TEST_DIG), dec!(2.5));
+    // W_0(-2/5) == InputOutOfRange, W_-1(1/10) == InputOutOfRange, W_-1(0) == Pole
+    let res12 = d_lambert_w0(dec!(-0.4), TEST_ITER).map_err(|e| e.kind());
+    assert_eq!(res12, Err(ErrorKind::InputOutOfRange));
+    let res13 = d_lambert_wm1(dec!(0.1), TEST_ITER).map_err(|e| e.kind());
+    assert_eq!(res13, Err(ErrorKind::InputOutOfRange));
+    let res14 = d_lambert_wm1(D0, TEST_ITER).map_err(|e| e.kind());
+    assert_eq!(res14, Err(ErrorKind::Pole));
+    // Return Ok
+    Ok(())
+}
+
+//##########################################################################################################################
+
+#[test]
+fn bessel() -> Result<(), Error> {
+    // J_0(0) == 1, J_2(0) == 0, I_0(0) == 1
+    let res1 = d_bessel_j(D0, D0, TEST_ITER)?;
+    assert_eq!(res1, D1);
+    let res2 = d_bessel_j(dec!(2), D0, TEST_ITER)?;
+    assert_eq!(res2, D0);
+    let res3 = d_bessel_i(D0, D0, TEST_ITER)?;
+    assert_eq!(res3, D1);
+    // J_0(1) == J_0(1), J_3(7) == J_3(7), by the power series and Miller's recurrence
+    let res4 = d_bessel_j(D0, D1, TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res4, BESSEL_J0_1.round_dp(TEST_DIG));
+    let res5 = d_bessel_j(dec!(3), dec!(7), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res5, BESSEL_J3_7.round_dp(TEST_DIG));
+    // J_1(10) == J_1(10), J_5(20) == J_5(20)
+    let res6 = d_bessel_j(D1, dec!(10), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res6, BESSEL_J1_10.round_dp(TEST_DIG));
+    let res7 = d_bessel_j(dec!(5), dec!(20), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res7, BESSEL_J5_20.round_dp(TEST_DIG));
+    // J_0(50) == J_0(50), by Hankel's expansion
+    let res8 = d_bessel_j(D0, dec!(50), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res8, BESSEL_J0_50.round_dp(TEST_DIG));
+    // J_-3(7) == -J_3(7), J_-2.7(12.3) == J_-2.7(12.3)
+    let res9 = d_bessel_j(dec!(-3), dec!(7), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res9, -BESSEL_J3_7.round_dp(TEST_DIG));
+    let res10 = d_bessel_j(dec!(-2.7), dec!(12.3), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res10, BESSEL_JN27DIV10_123DIV10.round_dp(TEST_DIG));
+    // J_1/2(π/2) == 2/π
+    let res11 = d_bessel_j(D1DIV2, PI / dec!(2), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res11, (dec!(2) / PI).round_dp(TEST_DIG));
+    // Y_0(1) == Y_0(1), Y_1(10) == Y_1(10), Y_3(40) == Y_3(40)
+    let res12 = d_bessel_y(D0, D1, TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res12, BESSEL_Y0_1.round_dp(TEST_DIG));
+    let res13 = d_bessel_y(D1, dec!(10), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res13, BESSEL_Y1_10.round_dp(TEST_DIG));
+    let res14 = d_bessel_y(dec!(3), dec!(40), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res14, BESSEL_Y3_40.round_dp(TEST_DIG));
+    // Y_0.3(5) == Y_0.3(5)
+    let res15 = d_bessel_y(dec!(0.3), dec!(5), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res15, BESSEL_Y3DIV10_5.round_dp(TEST_DIG));
+    // J_1(x) * Y_0(x) - J_0(x) * Y_1(x) == 2 / (πx)
+    let x = dec!(5.5);
+    let wronskian = (d_bessel_j(D1, x, TEST_ITER)? * d_bessel_y(D0, x, TEST_ITER)?)
+        - (d_bessel_j(D0, x, TEST_ITER)? * d_bessel_y(D1, x, TEST_ITER)?);
+    assert_eq!(wronskian.round_dp(TEST_DIG), (dec!(2) / (PI * x)).round_dp(TEST_DIG));
+    // I_0(1) == I_0(1), I_1(10) == I_1(10), I_2.5(3) == I_2.5(3)
+    let res16 = d_bessel_i(D0, D1, TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res16, BESSEL_I0_1.round_dp(TEST_DIG));
+    let res17 = d_bessel_i(D1, dec!(10), TEST_ITER)?.round_dp(20);
+    assert_eq!(res17, BESSEL_I1_10.round_dp(20));
+    let res18 = d_bessel_i(dec!(2.5), dec!(3), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res18, BESSEL_I5DIV2_3.round_dp(TEST_DIG));
+    // K_0(1) == K_0(1), K_1(5) == K_1(5), K_2(30) == K_2(30)
+    let res19 = d_bessel_k(D0, D1, TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res19, BESSEL_K0_1.round_dp(TEST_DIG));
+    let res20 = d_bessel_k(D1, dec!(5), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res20, BESSEL_K1_5.round_dp(TEST_DIG));
+    let res21 = d_bessel_k(dec!(2), dec!(30), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res21, BESSEL_K2_30.round_dp(TEST_DIG));
+    // K_-1(5) == K_1(5)
+    let res22 = d_bessel_k(-D1, dec!(5), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res22, BESSEL_K1_5.round_dp(TEST_DIG));
+    // Y_0(0) == Pole, J_-1/2(0) == Pole
+    let res23 = d_bessel_y(D0, D0, TEST_ITER).map_err(|e| e.kind());
+    assert_eq!(res23, Err(ErrorKind::Pole));
+    let res24 = d_bessel_j(-D1DIV2, D0, TEST_ITER).map_err(|e| e.kind());
+    assert_eq!(res24, Err(ErrorKind::Pole));
+    // J_1/2(-1) == InputOutOfRange, K_0(-1) == InputOutOfRange, J_129(1) == InputOutOfRange
+    let res25 = d_bessel_j(D1DIV2, -D1, TEST_ITER).map_err(|e| e.kind());
+    assert_eq!(res25, Err(ErrorKind::InputOutOfRange));
+    let res26 = d_bessel_k(D0, -D1, TEST_ITER).map_err(|e| e.kind());
+    assert_eq!(res26, Err(ErrorKind::InputOutOfRange));
+    let res27 = d_bessel_j(dec!(129), D1, TEST_ITER).map_err(|e| e.kind());
+    assert_eq!(res27, Err(ErrorKind::InputOutOfRange));
+    // K_0.999999(1) == K_0.999999(1), K_2.0000001(1) == K_2.0000001(1), next to the integer orders
+    let res28 = d_bessel_k(dec!(0.999999), D1, TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res28, BESSEL_K999999DIV1000000_1.round_dp(TEST_DIG));
+    let res29 = d_bessel_k(dec!(2.0000001), D1, TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res29, BESSEL_K20000001DIV10000000_1.round_dp(TEST_DIG));
+    // K_0.3(0.1) == K_0.3(0.1)
+    let res30 = d_bessel_k(dec!(0.3), dec!(0.1), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res30, BESSEL_K3DIV10_1DIV10.round_dp(TEST_DIG));
+    // Return Ok
+    Ok(())
+}
+
+//##########################################################################################################################
+
+#[test]
+fn expint() -> Result<(), Error> {
+    // Ei(1) == Ei(1), Ei(1/2) == Ei(1/2), by the power series
+    let res1 = d_ei(D1, TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res1, EI_1.round_dp(TEST_DIG));
+    let res2 = d_ei(D1DIV2, TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res2, EI_1DIV2.round_dp(TEST_DIG));
+    // Ei(10) == Ei(10), Ei(50) == Ei(50), with x^n / n! built up term by term
+    let res3 = d_ei(dec!(10), TEST_ITER)?.round_dp(20);
+    assert_eq!(res3, EI_10.round_dp(20));
+    let res4 = d_ei(dec!(50), TEST_ITER)?.round_dp(6);
+    assert_eq!(res4, EI_50.round_dp(6));
+    // Ei(68) == Ei(68) to 24 significant digits, by the asymptotic expansion
+    let res5 = d_ei(dec!(68), TEST_ITER)?;
+    assert_eq!((res5 / EI_68).round_dp(TEST_DIG), D1);
+    // Ei(-5/2) == Ei(-5/2), by the continued fraction of E1(5/2)
+    let res6 = d_ei(dec!(-2.5), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res6, EI_N5DIV2.round_dp(TEST_DIG));
+    // E1(1/10) == E1(1/10), E1(5) == E1(5), E1(30) == E1(30)
+    let res7 = d_e1(dec!(0.1), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res7, E1_1DIV10.round_dp(TEST_DIG));
+    let res8 = d_e1(dec!(5), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res8, E1_5.round_dp(TEST_DIG));
+    let res9 = d_e1(dec!(30), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res9, E1_30.round_dp(TEST_DIG));
+    // Ei(-3) == -E1(3)
+    let res10 = d_ei(dec!(-3), TEST_ITER)?;
+    assert_eq!(res10, -d_e1(dec!(3), TEST_ITER)?);
+    // E1(70) == 0, below the scale of a Decimal
+    let res11 = d_e1(dec!(70), TEST_ITER)?;
+    assert_eq!(res11, D0);
+    // li(10) == li(10), li(1/2) == li(1/2)
+    let res12 = d_li(dec!(10), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res12, LI_10.round_dp(TEST_DIG));
+    let res13 = d_li(D1DIV2, TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res13, LI_1DIV2.round_dp(TEST_DIG));
+    // li(10^6) == li(10^6), li(10^20) == li(10^20)
+    let res14 = d_li(dec!(1_000_000), TEST_ITER)?.round_dp(20);
+    assert_eq!(res14, LI_1E6.round_dp(20));
+    let res15 = d_li(dec!(100_000_000_000_000_000_000), TEST_ITER)?.round_dp(6);
+    assert_eq!(res15, LI_1E20.round_dp(6));
+    // li(0) == 0, Li(2) == 0, Li(1000) == li(1000) - li(2)
+    let res16 = d_li(D0, TEST_ITER)?;
+    assert_eq!(res16, D0);
+    let res17 = d_Li(dec!(2), TEST_ITER)?;
+    assert_eq!(res17, D0);
+    let res18 = d_Li(dec!(1000), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res18, LI_OFFSET_1000.round_dp(TEST_DIG));
+    // Ei(0) == Pole, E1(0) == Pole, li(1) == Pole
+    let res19 = d_ei(D0, TEST_ITER).map_err(|e| e.kind());
+    assert_eq!(res19, Err(ErrorKind::Pole));
+    let res20 = d_e1(D0, TEST_ITER).map_err(|e| e.kind());
+    assert_eq!(res20, Err(ErrorKind::Pole));
+    let res21 = d_li(D1, TEST_ITER).map_err(|e| e.kind());
+    assert_eq!(res21, Err(ErrorKind::Pole));
+    // E1(-1) == InputOutOfRange, li(-1) == InputOutOfRange
+    let res22 = d_e1(-D1, TEST_ITER).map_err(|e| e.kind());
+    assert_eq!(res22, Err(ErrorKind::InputOutOfRange));
+    let res23 = d_li(-D1, TEST_ITER).map_err(|e| e.kind());
+    assert_eq!(res23, Err(ErrorKind::InputOutOfRange));
+    // Return Ok
+    Ok(())
+}
+
+//##########################################################################################################################
+
+#[test]
+fn edge_inputs() -> Result<(), Error> {
+    // Set Variables
+    let tiny = Decimal::new(1, 28);
+    let values = [
+        D0, tiny, -tiny, D1DIV2, -D1DIV2, dec!(40), dec!(-40.5), dec!(28.9),
+        Decimal::MAX, Decimal::MIN,
+    ];
+    // Every public function returns instead of panicking
+    for value in values {
+        if let Err(err) = d_gamma(value, TEST_ITER) { assert_eq!(err.func(), Some("d_gamma")) };
+        if let Err(err) = d_lgamma(value, TEST_ITER) { assert_eq!(err.func(), Some("d_lgamma")) };
+        if let Err(err) = d_rgamma(value, TEST_ITER) { assert_eq!(err.func(), Some("d_rgamma")) };
+        if let Err(err) = d_erf(value, TEST_ITER) { assert_eq!(err.func(), Some("d_erf")) };
+        if let Err(err) = d_erfc(value, TEST_ITER) { assert_eq!(err.func(), Some("d_erfc")) };
+        if let Err(err) = d_erfcx(value, TEST_ITER) { assert_eq!(err.func(), Some("d_erfcx")) };
+        if let Err(err) = d_erfinv(value, TEST_ITER) { assert_eq!(err.func(), Some("d_erfinv")) };
+        if let Err(err) = d_lambert_w0(value, TEST_ITER) { assert_eq!(err.func(), Some("d_lambert_w0")) };
+        if let Err(err) = d_lambert_wm1(value, TEST_ITER) { assert_eq!(err.func(), Some("d_lambert_wm1")) };
+        if let Err(err) = d_ei(value, TEST_ITER) { assert_eq!(err.func(), Some("d_ei")) };
+        if let Err(err) = d_e1(value, TEST_ITER) { assert_eq!(err.func(), Some("d_e1")) };
+        if let Err(err) = d_li(value, TEST_ITER) { assert_eq!(err.func(), Some("d_li")) };
+        if let Err(err) = d_Li(value, TEST_ITER) { assert_eq!(err.func(), Some("d_Li")) };
+        for other in values {
+            if let Err(err) = d_beta(value, other, TEST_ITER) { assert_eq!(err.func(), Some("d_beta")) };
+            if let Err(err) = d_gamma_lower(value, other, TEST_ITER) { assert_eq!(err.func(), Some("d_gamma_lower")) };
+            if let Err(err) = d_gamma_upper(value, other, TEST_ITER) { assert_eq!(err.func(), Some("d_gamma_upper")) };
+            if let Err(err) = d_gamma_p(value, other, TEST_ITER) { assert_eq!(err.func(), Some("d_gamma_p")) };
+            if let Err(err) = d_gamma_q(value, other, TEST_ITER) { assert_eq!(err.func(), Some("d_gamma_q")) };
+            if let Err(err) = d_beta_inc(value, other, D1DIV2, TEST_ITER) { assert_eq!(err.func(), Some("d_beta_inc")) };
+            if let Err(err) = d_bessel_j(value, other, TEST_ITER) { assert_eq!(err.func(), Some("d_bessel_j")) };
+            if let Err(err) = d_bessel_y(value, other, TEST_ITER) { assert_eq!(err.func(), Some("d_bessel_y")) };
+            if let Err(err) = d_bessel_i(value, other, TEST_ITER) { assert_eq!(err.func(), Some("d_bessel_i")) };
+            if let Err(err) = d_bessel_k(value, other, TEST_ITER) { assert_eq!(err.func(), Some("d_bessel_k")) };
+        };
+    };
+    // Return Ok
+    Ok(())
+}
+
+//##########################################################################################################################
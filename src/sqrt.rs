@@ -9,8 +9,9 @@ use crate::constants::{ SQRT_LOWER_BD, SQRT_LOWER_VAL };
 use crate::constants::{ SQRT_UPPER_BD_P, SQRT_UPPER_VAL_P };
 use crate::constants::{ SQRT_LOWER_BD_P, SQRT_LOWER_VAL_P };
 
-use crate::error::Error;
+use crate::error::{ Error, ErrorKind };
 use crate::factorial::{ m_fac };
+use crate::basic::{ Terms, d_series };
 
 use crate::multiplex::types::{ Multiplex };
 use crate::multiplex::basic::{ m_pow, ma_pow };
@@ -43,34 +44,34 @@ fn sqrt_prepare(
     let mut base: Decimal = D1;
     loop {
         if rem > D4 {
-            rem = rem / D4;
-            base = base * D2;
+            rem /= D4;
+            base *= D2;
         }
         else if rem < D1DIV4 {
-            rem = rem * D4;
-            base = base / D2;
+            rem *= D4;
+            base /= D2;
         }
         else {break}
     };
     loop {
         if rem > SQRT_UPPER_BD {
-            rem = rem / SQRT_UPPER_BD;
-            base = base * SQRT_UPPER_VAL;
+            rem /= SQRT_UPPER_BD;
+            base *= SQRT_UPPER_VAL;
         }
         else if rem < SQRT_LOWER_BD {
-            rem = rem / SQRT_LOWER_BD;
-            base = base * SQRT_LOWER_VAL;
+            rem /= SQRT_LOWER_BD;
+            base *= SQRT_LOWER_VAL;
         }
         else {break}
     };
     loop {
         if rem > SQRT_UPPER_BD_P {
-            rem = rem / SQRT_UPPER_BD_P;
-            base = base * SQRT_UPPER_VAL_P;
+            rem /= SQRT_UPPER_BD_P;
+            base *= SQRT_UPPER_VAL_P;
         }
         else if rem < SQRT_LOWER_BD_P {
-            rem = rem / SQRT_LOWER_BD_P;
-            base = base * SQRT_LOWER_VAL_P;
+            rem /= SQRT_LOWER_BD_P;
+            base *= SQRT_LOWER_VAL_P;
         }
         else {break}
     };
@@ -83,57 +84,76 @@ fn sqrt_prepare(
 #[inline]
 fn sqrt_series(
     value: Decimal,
-    terms: usize
-) -> Result<Decimal, Error> {
+    terms: Terms
+) -> Result<(Decimal, usize), Error> {
     let mut acc1: (Multiplex, usize) = (Multiplex::new(), 0);
     let mut acc2: (Multiplex, usize) = (Multiplex::new(), 0);
     // Iterate over Taylor Series
-    (1..=terms).into_iter()
-        .map(|n| Ok(
+    d_series(1, terms, |n|
+        (
             (
-                (
-                    value *
-                    m_fac(2 * (n - 1))? *
-                    ma_pow(D1 - value, n - 1, &mut acc1)?
-                ) /
-                m_pow(
-                    m_fac(n - 1)? *
-                    ma_pow(D2, n - 1, &mut acc2)?,
-                    2
-                )?
-            ).squash()?
-        ))
-        .reduce(|u, d| Ok(
-            u?.checked_add(d?).ok_or(Error::AddOverflow)?
-        ))
-        .unwrap_or(Ok(D0))
+                value *
+                m_fac(2 * (n - 1))? *
+                ma_pow(D1 - value, n - 1, &mut acc1)?
+            ) /
+            m_pow(
+                m_fac(n - 1)? *
+                ma_pow(D2, n - 1, &mut acc2)?,
+                2
+            )?
+        ).squash()
+    )
 }
  
 //##########################################################################################################################
 
 #[inline]
-pub fn d_sqrt(
+pub(crate) fn d_sqrt_helper(
     value: Decimal,
-    terms: usize
-) -> Result<Decimal, Error> {
-    if value < D0 { Err(Error::InputOutOfRange)? };
+    terms: Terms
+) -> Result<(Decimal, usize), Error> {
+    if value < D0 { Err(ErrorKind::InputOutOfRange)? };
     Ok(
-             if value == D0 {D0}
-        else if value == D1 {D1}
+             if value == D0 {(D0, 0)}
+        else if value == D1 {(D1, 0)}
         else {
             let (rem, base) = sqrt_prepare(value);
-            base * sqrt_series(rem, terms)?
+            let (root, used) = sqrt_series(rem, terms)?;
+            // sqrt_prepare leaves |1 - x| up to 0.05, so n terms are good to about 0.05^n;
+            // one Newton step y = (y + x / y) / 2 squares that error
+            (base * ((root + (rem / root)) / D2), used)
         }
     )
 }
 
+#[inline]
+pub fn d_sqrt(
+    value: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    d_sqrt_helper(value, Terms::Fixed(terms))
+        .map(|(res, _)| res)
+        .map_err(|e| e.context("d_sqrt", value))
+}
+
+/// sqrt(x) to `digits` decimal places, with the number of series terms used.
+#[inline]
+pub fn d_sqrt_dp(
+    value: Decimal,
+    digits: u32
+) -> Result<(Decimal, usize), Error> {
+    if digits > 28 { Err(Error::from(ErrorKind::InputOutOfRange).context("d_sqrt_dp", digits))? };
+    d_sqrt_helper(value, Terms::Digits(digits))
+        .map_err(|e| e.context("d_sqrt_dp", value))
+}
+
 //##########################################################################################################################
 
 #[inline]
 fn i_sqrt_100(
     value: Decimal
 ) -> Result<Decimal, Error> {
-    let vu: u8 = value.to_u8().ok_or(Error::InputOutOfRange)?;
+    let vu: u8 = value.to_u8().ok_or(ErrorKind::InputOutOfRange)?;
     Ok(
         match vu {
               0 => { D0  },
@@ -147,7 +167,7 @@ fn i_sqrt_100(
              64 => { D8  },
              81 => { D9  },
             100 => { D10 },
-              _ => { Err(Error::InputOutOfRange)? },
+              _ => { Err(ErrorKind::InputOutOfRange)? },
         }
     )
 }
@@ -163,14 +183,14 @@ fn i_sqrt_helper(
     let mut right: Decimal = value;
     // Loop until value is reached
     loop {
-        if left > right {break Err(Error::InputOutOfRange)};
+        if left > right {break Err(ErrorKind::InputOutOfRange.into())};
         // Set Variables
-        let mid = ((left + right) / D2).round();
-        let mid_sqr = mid * mid;
+        let mid = (left + ((right - left) / D2)).round();
+        let mid_sqr = mid.checked_mul(mid);
         // Check if mid is perfect square
-        if mid_sqr == value {break Ok(mid)};
+        if mid_sqr == Some(value) {break Ok(mid)};
         // Mid is small -> go right to increase mid
-        if mid_sqr < value { left = mid + D1; }
+        if mid_sqr.is_some_and(|v| v < value) { left = mid + D1; }
         // Mid is large -> go left to decrease mid
         else { right = mid - D1; };
     }
@@ -183,10 +203,11 @@ fn i_sqrt_helper(
 pub fn i_sqrt(
     value: Decimal
 ) -> Result<Decimal, Error> {
-    if value.fract() != D0 { Err(Error::InputOutOfRange)? };
-    if value < D0 { Err(Error::InputOutOfRange)? };
-    if value <= D100 { i_sqrt_100(value) }
-    else { i_sqrt_helper(value) }
+    let res =
+             if (value.fract() != D0) || (value < D0) { Err(ErrorKind::InputOutOfRange.into()) }
+        else if value <= D100 { i_sqrt_100(value) }
+        else                  { i_sqrt_helper(value) };
+    res.map_err(|e| e.context("i_sqrt", value))
 }
 
 //##########################################################################################################################
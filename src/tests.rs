@@ -4,30 +4,85 @@ use rust_decimal::prelude::*;
 use rust_decimal_macros::dec;
 
 // Modules
-use crate::constants::{ E_SQR };
+use crate::constants::{ E_SQR, LN_2, PI, PIDIV2, d_pi, d_pi_dp };
 
-use crate::error::Error;
-use crate::sqrt::{ i_sqrt, d_sqrt };
-use crate::euler::{ d_exp, d_ln };
-use crate::basic::{ dd_pow };
-use crate::trigonometry::{ d_cos, d_sin, d_atan, d_atan2 };
+use crate::error::{ Error, ErrorKind };
+use crate::sqrt::{ i_sqrt, d_sqrt, d_sqrt_dp };
+use crate::euler::{ d_exp, d_ln, d_exp_dp, d_ln_dp };
+use crate::basic::{ d_pow, dd_pow };
+use crate::factorial::{ d_fac, m_fac };
+use crate::hyperbolic::{ d_sinh, d_cosh, d_tanh, d_coth, d_sech, d_csch };
+use crate::hyperbolic::{ d_asinh, d_acosh, d_atanh };
+use crate::hyperbolic::{ d_sinh_dp, d_cosh_dp, d_tanh_dp, d_coth_dp, d_sech_dp, d_csch_dp };
+use crate::hyperbolic::{ d_asinh_dp, d_acosh_dp, d_atanh_dp };
+use crate::trigonometry::{ d_cos, d_sin, d_atan, d_atan2, d_asin, d_acos, d_atan2_dp };
+use crate::trigonometry::{ d_tan, d_cot, d_sec, d_csc };
+use crate::trigonometry::{ d_cos_dp, d_sin_dp, d_tan_dp, d_cot_dp, d_sec_dp, d_csc_dp };
+use crate::trigonometry::{ d_atan_dp, d_asin_dp, d_acos_dp };
+use crate::multiplex::types::{ Multiplex };
 
 //##########################################################################################################################
 
 // Constants
+const D0: Decimal = Decimal::ZERO;
 const D1: Decimal = Decimal::ONE;
 const D2: Decimal = Decimal::TWO;
 
+const D3: Decimal = dec!(3);
 const D4: Decimal = dec!(4);
+const D40: Decimal = dec!(40);
+const D100: Decimal = dec!(100);
 const D1DIV2: Decimal = dec!(0.5);
+const D1DIV4: Decimal = dec!(0.25);
+const D1DIV10: Decimal = dec!(0.1);
+const D1DIV20: Decimal = dec!(0.05);
+const D1DIV10000: Decimal = dec!(0.0001);
+const D95DIV100: Decimal = dec!(0.95);
+const D105DIV100: Decimal = dec!(1.05);
+const D3DIV10: Decimal = dec!(0.3);
+const D7DIV10: Decimal = dec!(0.7);
+const D9DIV10: Decimal = dec!(0.9);
 
 //##########################################################################################################################
 
 const SQRT_2: Decimal = dec!(1.4142135623730950488016887242);
+const SQRT_3: Decimal = dec!(1.7320508075688772935274463415);
+const SQRT_7DIV10: Decimal = dec!(0.8366600265340755479781720258);
+const SQRT_21DIV20: Decimal = dec!(1.0246950765959598383221038681);
+
+const LN_1E_N20: Decimal = dec!(-46.051701859880913680359829094);
 
 const SIN_1: Decimal = dec!(0.8414709848078965066525023216);
 const COS_1: Decimal = dec!(0.5403023058681397174009366074);
 const TAN_1: Decimal = dec!(1.5574077246549022305069748075);
+const COT_1: Decimal = dec!(0.6420926159343307030064199866);
+const SEC_1: Decimal = dec!(1.8508157176809256179117532414);
+const CSC_1: Decimal = dec!(1.1883951057781212162615994524);
+
+const ASIN_0P3: Decimal = dec!(0.3046926540153975079720029612);
+const ASIN_N0P9: Decimal = dec!(-1.1197695149986341866866770558);
+const ACOS_0P7: Decimal = dec!(0.7953988301841435554909683389);
+const ACOS_N0P9: Decimal = dec!(2.6905658417935308059179987475);
+
+const SINH_1: Decimal = dec!(1.1752011936438014568823818506);
+const COSH_1: Decimal = dec!(1.5430806348152437784779056208);
+const TANH_0P5: Decimal = dec!(0.4621171572600097585023184836);
+const COTH_2: Decimal = dec!(1.0373147207275480958778097648);
+const SECH_3: Decimal = dec!(0.0993279274194332078290124481);
+const CSCH_0P25: Decimal = dec!(3.9586351633020003602075596131);
+
+const ASINH_1: Decimal = dec!(0.881373587019543025232609325);
+const ASINH_N0P05: Decimal = dec!(-0.0499791900693486652313949621);
+const ACOSH_2: Decimal = dec!(1.3169578969248167086250463473);
+const ACOSH_1P0001: Decimal = dec!(0.0141420177752523242440634748);
+const ATANH_0P3: Decimal = dec!(0.3095196042031117154740673491);
+const ATANH_N0P95: Decimal = dec!(-1.8317808230648232137243663392);
+
+//##########################################################################################################################
+
+// Function Signatures
+type Unary = fn(Decimal, usize) -> Result<Decimal, Error>;
+type Digits = fn(Decimal, u32) -> Result<(Decimal, usize), Error>;
 
 //##########################################################################################################################
 
@@ -68,6 +123,14 @@ fn sqrt() -> Result<(), Error> {
     // sqrt(2) == sqrt(2)
     let res3 = d_sqrt(D2, TEST_ITER)?.round_dp(TEST_DIG);
     assert_eq!(res3, _sqrt_2_std);
+    // sqrt(3) == sqrt(3), sqrt(7/10) == sqrt(7/10), where the series alone is off from the 22nd digit
+    let res4 = d_sqrt(D3, TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res4, SQRT_3.round_dp(TEST_DIG));
+    let res5 = d_sqrt(D7DIV10, TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res5, SQRT_7DIV10.round_dp(TEST_DIG));
+    // sqrt(1.05) == sqrt(1.05) in 10 terms, at the edge of the reduced range where the series alone gives 13 digits
+    let res6 = d_sqrt(D105DIV100, 10)?.round_dp(TEST_DIG);
+    assert_eq!(res6, SQRT_21DIV20.round_dp(TEST_DIG));
     // Return Ok
     Ok(())
 }
@@ -84,6 +147,9 @@ fn euler() -> Result<(), Error> {
     // ln(e ^ 2) == 2
     let res2 = d_ln(E_SQR, TEST_ITER)?.round_dp(TEST_DIG);
     assert_eq!(res2, D2);
+    // ln(10 ^ -20) == -20 * ln(10)
+    let res3 = d_ln(dec!(0.00000000000000000001), TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res3, LN_1E_N20.round_dp(TEST_DIG));
     // Return Ok
     Ok(())
 }
@@ -107,6 +173,250 @@ fn trigonometry() -> Result<(), Error> {
     // atan2(cos(1), sin(1)) == 1
     let res4 = d_atan2(COS_1, SIN_1, TEST_ITER)?.round_dp(TEST_DIG);
     assert_eq!(res4, D1);
+    // asin(0.3) == asin(0.3)
+    let res5 = d_asin(D3DIV10, TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res5, ASIN_0P3.round_dp(TEST_DIG));
+    // asin(-0.9) == asin(-0.9)
+    let res6 = d_asin(-D9DIV10, TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res6, ASIN_N0P9.round_dp(TEST_DIG));
+    // acos(0.7) == acos(0.7)
+    let res7 = d_acos(D7DIV10, TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res7, ACOS_0P7.round_dp(TEST_DIG));
+    // acos(-0.9) == acos(-0.9)
+    let res8 = d_acos(-D9DIV10, TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res8, ACOS_N0P9.round_dp(TEST_DIG));
+    // asin(-1) == -pi/2
+    let res9 = d_asin(-D1, TEST_ITER)?;
+    assert_eq!(res9, -PIDIV2);
+    // acos(1.1) == InputOutOfRange
+    let res10 = d_acos(D1 + D1DIV10, TEST_ITER);
+    assert_eq!(res10.map_err(|e| e.kind()), Err(ErrorKind::InputOutOfRange));
+    // tan(1) == tan(1)
+    let res11 = d_tan(D1, TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res11, TAN_1.round_dp(TEST_DIG));
+    // cot(1) == cot(1)
+    let res12 = d_cot(D1, TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res12, COT_1.round_dp(TEST_DIG));
+    // sec(1) == sec(1)
+    let res13 = d_sec(D1, TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res13, SEC_1.round_dp(TEST_DIG));
+    // csc(1) == csc(1)
+    let res14 = d_csc(D1, TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res14, CSC_1.round_dp(TEST_DIG));
+    // tan(-pi/2) == Pole
+    let res15 = d_tan(-PIDIV2, TEST_ITER);
+    assert_eq!(res15.map_err(|e| e.kind()), Err(ErrorKind::Pole));
+    // tan(3pi/2) == Pole, tan(-3pi/2) == Pole, where the reduced argument is off by an ulp
+    let res16 = d_tan(D3 * PIDIV2, TEST_ITER);
+    assert_eq!(res16.map_err(|e| e.kind()), Err(ErrorKind::Pole));
+    let res17 = d_tan(-D3 * PIDIV2, TEST_ITER);
+    assert_eq!(res17.map_err(|e| e.kind()), Err(ErrorKind::Pole));
+    // sec(3pi/2) == Pole, cot(4pi) == Pole
+    let res18 = d_sec(D3 * PIDIV2, TEST_ITER);
+    assert_eq!(res18.map_err(|e| e.kind()), Err(ErrorKind::Pole));
+    let res19 = d_cot(D4 * PI, TEST_ITER);
+    assert_eq!(res19.map_err(|e| e.kind()), Err(ErrorKind::Pole));
+    // tan(3pi/2 - 1/1000) == cot(1/1000)
+    let res20 = d_tan((D3 * PIDIV2) - dec!(0.001), TEST_ITER)?.round_dp(20);
+    assert_eq!(res20, d_cot(dec!(0.001), TEST_ITER)?.round_dp(20));
+    // Return Ok
+    Ok(())
+}
+
+//##########################################################################################################################
+
+#[test]
+fn hyperbolic() -> Result<(), Error> {
+    // sinh(1) == sinh(1)
+    let res1 = d_sinh(D1, TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res1, SINH_1.round_dp(TEST_DIG));
+    // cosh(1) == cosh(1)
+    let res2 = d_cosh(D1, TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res2, COSH_1.round_dp(TEST_DIG));
+    // tanh(0.5) == tanh(0.5)
+    let res3 = d_tanh(D1DIV2, TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res3, TANH_0P5.round_dp(TEST_DIG));
+    // coth(2) == coth(2)
+    let res4 = d_coth(D2, TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res4, COTH_2.round_dp(TEST_DIG));
+    // sech(3) == sech(3)
+    let res5 = d_sech(D3, TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res5, SECH_3.round_dp(TEST_DIG));
+    // csch(0.25) == csch(0.25)
+    let res6 = d_csch(D1DIV4, TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res6, CSCH_0P25.round_dp(TEST_DIG));
+    // tanh(-40) == -1
+    let res7 = d_tanh(-D40, TEST_ITER)?;
+    assert_eq!(res7, -D1);
+    // asinh(1) == asinh(1)
+    let res8 = d_asinh(D1, TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res8, ASINH_1.round_dp(TEST_DIG));
+    // asinh(-0.05) == asinh(-0.05)
+    let res9 = d_asinh(-D1DIV20, TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res9, ASINH_N0P05.round_dp(TEST_DIG));
+    // acosh(2) == acosh(2)
+    let res10 = d_acosh(D2, TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res10, ACOSH_2.round_dp(TEST_DIG));
+    // acosh(1.0001) == acosh(1.0001)
+    let res11 = d_acosh(D1 + D1DIV10000, TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res11, ACOSH_1P0001.round_dp(TEST_DIG));
+    // atanh(0.3) == atanh(0.3)
+    let res12 = d_atanh(D3DIV10, TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res12, ATANH_0P3.round_dp(TEST_DIG));
+    // atanh(-0.95) == atanh(-0.95)
+    let res13 = d_atanh(-D95DIV100, TEST_ITER)?.round_dp(TEST_DIG);
+    assert_eq!(res13, ATANH_N0P95.round_dp(TEST_DIG));
+    // acosh(0.5) == InputOutOfRange
+    let res14 = d_acosh(D1DIV2, TEST_ITER);
+    assert_eq!(res14.map_err(|e| e.kind()), Err(ErrorKind::InputOutOfRange));
+    // atanh(1) == Pole
+    let res15 = d_atanh(D1, TEST_ITER);
+    assert_eq!(res15.map_err(|e| e.kind()), Err(ErrorKind::Pole));
+    // atanh(-2) == InputOutOfRange
+    let res16 = d_atanh(-D2, TEST_ITER);
+    assert_eq!(res16.map_err(|e| e.kind()), Err(ErrorKind::InputOutOfRange));
+    // Return Ok
+    Ok(())
+}
+
+//##########################################################################################################################
+
+#[test]
+fn precision() -> Result<(), Error> {
+    // Set Variables
+    let _e_sqr_std = E_SQR.round_dp(TEST_DIG);
+    let _sqrt_2_std = SQRT_2.round_dp(TEST_DIG);
+    let _sin1_std = SIN_1.round_dp(TEST_DIG);
+    // exp(2) == e^2
+    let (res1, _) = d_exp_dp(D2, TEST_DIG)?;
+    assert_eq!(res1.round_dp(TEST_DIG), _e_sqr_std);
+    // ln(2) == ln(2)
+    let (res2, used2) = d_ln_dp(D2, TEST_DIG)?;
+    assert_eq!(res2.round_dp(TEST_DIG), LN_2.round_dp(TEST_DIG));
+    assert!(used2 < 2 * TEST_ITER);
+    // sqrt(2) == sqrt(2)
+    let (res3, _) = d_sqrt_dp(D2, TEST_DIG)?;
+    assert_eq!(res3.round_dp(TEST_DIG), _sqrt_2_std);
+    // sin(1) == sin(1)
+    let (res4, _) = d_sin_dp(D1, TEST_DIG)?;
+    assert_eq!(res4.round_dp(TEST_DIG), _sin1_std);
+    // atan(tan(1)) == 1
+    let (res5, _) = d_atan_dp(TAN_1, TEST_DIG)?;
+    assert_eq!(res5.round_dp(TEST_DIG), D1);
+    // pi == pi
+    let (res6, _) = d_pi_dp(TEST_DIG)?;
+    assert_eq!(res6.round_dp(TEST_DIG), PI.round_dp(TEST_DIG));
+    // Fewer digits need fewer terms
+    let (_, used7) = d_ln_dp(D2, TEST_DIG / 2)?;
+    assert!(used7 < used2);
+    // exp(2) to 29 digits == InputOutOfRange
+    let res8 = d_exp_dp(D2, 29);
+    assert_eq!(res8.map_err(|e| e.kind()), Err(ErrorKind::InputOutOfRange));
+    // sinh(1) == sinh(1)
+    let (res9, _) = d_sinh_dp(D1, TEST_DIG)?;
+    assert_eq!(res9.round_dp(TEST_DIG), SINH_1.round_dp(TEST_DIG));
+    // tanh(0.5) == tanh(0.5)
+    let (res10, _) = d_tanh_dp(D1DIV2, TEST_DIG)?;
+    assert_eq!(res10.round_dp(TEST_DIG), TANH_0P5.round_dp(TEST_DIG));
+    // acosh(1.0001) == acosh(1.0001)
+    let (res11, _) = d_acosh_dp(D1 + D1DIV10000, TEST_DIG)?;
+    assert_eq!(res11.round_dp(TEST_DIG), ACOSH_1P0001.round_dp(TEST_DIG));
+    // atanh(-0.95) == atanh(-0.95)
+    let (res12, _) = d_atanh_dp(-D95DIV100, TEST_DIG)?;
+    assert_eq!(res12.round_dp(TEST_DIG), ATANH_N0P95.round_dp(TEST_DIG));
+    // Return Ok
+    Ok(())
+}
+
+//##########################################################################################################################
+
+#[test]
+fn errors() -> Result<(), Error> {
+    // sqrt(-1) == InputOutOfRange
+    let err1 = d_sqrt(-D1, TEST_ITER).unwrap_err();
+    assert_eq!(err1.kind(), ErrorKind::InputOutOfRange);
+    assert_eq!(err1.func(), Some("d_sqrt"));
+    assert_eq!(err1.input(), Some("-1"));
+    assert_eq!(err1.to_string(), "d_sqrt(-1): input out of range");
+    // exp(100) == MultiplyOverflow
+    let err2 = d_exp(D100, TEST_ITER).unwrap_err();
+    assert_eq!(err2.kind(), ErrorKind::MultiplyOverflow);
+    assert_eq!(err2.func(), Some("d_exp"));
+    // exp(-100) == 0, below the scale of a Decimal
+    let res3 = d_exp(-D100, TEST_ITER)?;
+    assert_eq!(res3, D0);
+    // coth(0) == Pole
+    let err4 = d_coth(D0, TEST_ITER).unwrap_err();
+    assert_eq!(err4.to_string(), "d_coth(0): pole of the function");
+    // 1 / 0 == DivisionByZero
+    let err5 = (Multiplex::new() / D0).squash().unwrap_err();
+    assert_eq!(err5.kind(), ErrorKind::DivisionByZero);
+    assert_eq!(err5.func(), None);
+    // Error implements std::error::Error
+    let err6: Box<dyn std::error::Error> = Box::new(err1);
+    assert_eq!(err6.to_string(), "d_sqrt(-1): input out of range");
+    // exp(66.6) == MultiplyOverflow
+    let err7 = d_exp(dec!(66.6), TEST_ITER).unwrap_err();
+    assert_eq!(err7.kind(), ErrorKind::MultiplyOverflow);
+    // exp(-66) == 0, exp(-66.6) == 0
+    let res8 = d_exp(dec!(-66), TEST_ITER)?;
+    assert_eq!(res8, D0);
+    let res9 = d_exp(dec!(-66.6), TEST_ITER)?;
+    assert_eq!(res9, D0);
+    // Return Ok
+    Ok(())
+}
+
+//##########################################################################################################################
+
+#[test]
+fn edge_inputs() -> Result<(), Error> {
+    // Set Variables
+    let tiny = Decimal::new(1, 28);
+    let huge = dec!(100000000000000000000);
+    let values = [
+        D0, D1, -D1, tiny, -tiny, huge, -huge, D40, -D40, D100, -D100,
+        Decimal::MAX, Decimal::MIN, PIDIV2, -PIDIV2, PI, -PI,
+    ];
+    let unary: [(&str, Unary); 20] = [
+        ("d_sqrt", d_sqrt), ("d_exp", d_exp), ("d_ln", d_ln),
+        ("d_cos", d_cos), ("d_sin", d_sin), ("d_tan", d_tan), ("d_cot", d_cot),
+        ("d_sec", d_sec), ("d_csc", d_csc), ("d_atan", d_atan),
+        ("d_asin", d_asin), ("d_acos", d_acos),
+        ("d_sinh", d_sinh), ("d_cosh", d_cosh), ("d_tanh", d_tanh), ("d_coth", d_coth),
+        ("d_sech", d_sech), ("d_csch", d_csch), ("d_asinh", d_asinh), ("d_atanh", d_atanh),
+    ];
+    let digits: [(&str, Digits); 21] = [
+        ("d_sqrt_dp", d_sqrt_dp), ("d_exp_dp", d_exp_dp), ("d_ln_dp", d_ln_dp),
+        ("d_cos_dp", d_cos_dp), ("d_sin_dp", d_sin_dp), ("d_tan_dp", d_tan_dp),
+        ("d_cot_dp", d_cot_dp), ("d_sec_dp", d_sec_dp), ("d_csc_dp", d_csc_dp),
+        ("d_atan_dp", d_atan_dp), ("d_asin_dp", d_asin_dp), ("d_acos_dp", d_acos_dp),
+        ("d_sinh_dp", d_sinh_dp), ("d_cosh_dp", d_cosh_dp), ("d_tanh_dp", d_tanh_dp),
+        ("d_coth_dp", d_coth_dp), ("d_sech_dp", d_sech_dp), ("d_csch_dp", d_csch_dp),
+        ("d_asinh_dp", d_asinh_dp), ("d_acosh_dp", d_acosh_dp), ("d_atanh_dp", d_atanh_dp),
+    ];
+    // Every public function returns instead of panicking
+    for value in values {
+        for (name, func) in unary {
+            if let Err(err) = func(value, TEST_ITER) { assert_eq!(err.func(), Some(name)) };
+        };
+        for (name, func) in digits {
+            if let Err(err) = func(value, TEST_DIG) { assert_eq!(err.func(), Some(name)) };
+        };
+        let _ = d_acosh(value, TEST_ITER);
+        let _ = d_atan2_dp(value, D0, TEST_DIG);
+        let _ = d_pow(value, 3);
+        let _ = i_sqrt(value);
+        let _ = (Multiplex::new() / value).squash();
+        for other in values {
+            let _ = d_atan2(value, other, TEST_ITER);
+            let _ = dd_pow(value, other, TEST_ITER);
+        };
+    };
+    let _ = d_fac(usize::MAX);
+    let _ = m_fac(usize::MAX);
+    let _ = d_pi(0);
+    let _ = d_pi_dp(0);
     // Return Ok
     Ok(())
 }
@@ -5,12 +5,14 @@ use rust_decimal_macros::dec;
 
 // Modules
 use crate::constants::{ PI, PIDIV2, PI3DIV2, PI2 };
+use crate::constants::{ PIDIV3, PI2DIV3 };
 use crate::constants::{ PIDIV4, PIDIV6, PIDIV18, PIDIV36 };
 use crate::constants::{ TAN_PIDIV6, TAN_PIDIV18, TAN_PIDIV36 };
 
-use crate::error::Error;
+use crate::error::{ Error, ErrorKind };
 use crate::factorial::{ m_fac };
-use crate::basic::{ dec, da_pow };
+use crate::sqrt::{ d_sqrt_helper };
+use crate::basic::{ Terms, dec, da_pow, d_series };
 
 use crate::multiplex::types::{ Multiplex };
 use crate::multiplex::basic::{ ma_pow };
@@ -25,9 +27,13 @@ const D0: Decimal = Decimal::ZERO;
 const D1: Decimal = Decimal::ONE;
 const D2: Decimal = Decimal::TWO;
 
+const D1DIV2: Decimal = dec!(0.5);
 const D1DIV5: Decimal = dec!(0.2);
 const D2DIV5: Decimal = dec!(0.4);
 
+// Distance from a pole, per multiple of π reduced away, below which the argument is taken as the pole
+const POLE_TOLERANCE: Decimal = dec!(0.000000000000000000000000001);
+
 const PI_PAIR: Pair = (DN1, D0);
 const PIDIV2_PAIR: Pair = (D0, D1);
 const PI3DIV2_PAIR: Pair = (D0, DN1);
@@ -51,14 +57,22 @@ fn sin_sub2(value: Pair, other: Pair) -> Decimal {
 /// tan(a + b) = sin(a + b) / cos(a + b)
 /// tan(a - b) = sin(a - b) / cos(a - b)
 #[inline]
-fn tan_sub2(value: Pair, other: Pair) -> Decimal {
-    sin_sub2(value, other) / cos_sub2(value, other)
+fn tan_sub2(value: Pair, other: Pair) -> Result<Decimal, Error> {
+    Ok(
+        sin_sub2(value, other)
+            .checked_div(cos_sub2(value, other))
+            .ok_or(ErrorKind::DivisionByZero)?
+    )
 }
 
 /// tan(a - b) = (tan(a) - tan(b)) / (1 + (tan(a) * tan(b)))
 #[inline]
-fn tan_sub(value: Decimal, other: Decimal) -> Decimal {
-    (value - other) / (D1 + (value * other))
+fn tan_sub(value: Decimal, other: Decimal) -> Result<Decimal, Error> {
+    let num = value.checked_sub(other).ok_or(ErrorKind::AddOverflow)?;
+    let den = value.checked_mul(other)
+        .and_then(|v| v.checked_add(D1))
+        .ok_or(ErrorKind::MultiplyOverflow)?;
+    Ok(num.checked_div(den).ok_or(ErrorKind::DivisionByZero)?)
 }
 
 //##########################################################################################################################
@@ -71,32 +85,38 @@ fn trig_prepare(
     if (rem < -PI) || (PI < rem) {
         rem = rem - ((rem / PI2).floor() * PI2);
     };
-         if rem >  PI { rem = rem - PI2; }
-    else if rem < -PI { rem = rem + PI2; };
+         if rem >  PI { rem -= PI2; }
+    else if rem < -PI { rem += PI2; };
     rem
 }
 
+/// Whether x reduces to `center` or `PI - center` (up to sign), within the precision of the reduction
+#[inline]
+fn trig_pole(
+    value: Decimal,
+    center: Decimal
+) -> bool {
+    let rem = trig_prepare(value).abs();
+    let tol = if value.abs() <= PI {D0} else {POLE_TOLERANCE * (value.abs() / PI)};
+    ((rem - center).abs() <= tol) || ((PI - rem - center).abs() <= tol)
+}
+
 //##########################################################################################################################
 
 /// sin(x) = sum(n=0; -1^n * (x^2n / 2n!))
 #[inline]
 fn cos_series(
     value: Decimal,
-    terms: usize
-) -> Result<Decimal, Error> {
+    terms: Terms
+) -> Result<(Decimal, usize), Error> {
     let mut acc1: (Decimal, usize) = (D1, 0);
     let mut acc2: (Multiplex, usize) = (Multiplex::new(), 0);
     // Iterate over Series
-    (0..terms).into_iter()
-        .map(|n| Ok(
-            da_pow(-D1, n, &mut acc1)? * (
-                ma_pow(value, 2 * n, &mut acc2)? / m_fac(2 * n)?
-            ).squash()?
-        ))
-        .reduce(|u, d| Ok(
-            u?.checked_add(d?).ok_or(Error::AddOverflow)?
-        ))
-        .unwrap_or(Ok(D0))
+    d_series(0, terms, |n| Ok(
+        da_pow(-D1, n, &mut acc1)? * (
+            ma_pow(value, 2 * n, &mut acc2)? / m_fac(2 * n)?
+        ).squash()?
+    ))
 }
 
 //##########################################################################################################################
@@ -105,21 +125,16 @@ fn cos_series(
 #[inline]
 fn sin_series(
     value: Decimal,
-    terms: usize
-) -> Result<Decimal, Error> {
+    terms: Terms
+) -> Result<(Decimal, usize), Error> {
     let mut acc1: (Decimal, usize) = (D1, 0);
     let mut acc2: (Multiplex, usize) = (Multiplex::new(), 0);
     // Iterate over Series
-    (0..terms).into_iter()
-        .map(|n| Ok(
-            da_pow(-D1, n, &mut acc1)? * (
-                ma_pow(value, (2 * n) + 1, &mut acc2)? / m_fac((2 * n) + 1)?
-            ).squash()?
-        ))
-        .reduce(|u, d| Ok(
-            u?.checked_add(d?).ok_or(Error::AddOverflow)?
-        ))
-        .unwrap_or(Ok(D0))
+    d_series(0, terms, |n| Ok(
+        da_pow(-D1, n, &mut acc1)? * (
+            ma_pow(value, (2 * n) + 1, &mut acc2)? / m_fac((2 * n) + 1)?
+        ).squash()?
+    ))
 }
 
 //##########################################################################################################################
@@ -133,11 +148,11 @@ fn cos_prepare(
     let mut inv: bool = false;
     let mut rem: Decimal = value;
     // Fix Trigonometric period
-         if rem >=  PIDIV2 { inv = !inv; rem = rem - PI }
-    else if rem <  -PIDIV2 { inv = !inv; rem = rem + PI };
+         if rem >=  PIDIV2 { inv = !inv; rem -= PI }
+    else if rem <  -PIDIV2 { inv = !inv; rem += PI };
     // Redirect value into right series
-         if rem >=  PIDIV4 { inv = !inv; rem = rem - PIDIV2; sel = true; }
-    else if rem <  -PIDIV4 { inv =  inv; rem = rem + PIDIV2; sel = true; };
+         if rem >=  PIDIV4 { inv = !inv; rem -= PIDIV2; sel = true; }
+    else if rem <  -PIDIV4 {             rem += PIDIV2; sel = true; };
     // Return result
     (sel, inv, rem)
 }
@@ -153,11 +168,11 @@ fn sin_prepare(
     let mut inv: bool = false;
     let mut rem: Decimal = value;
     // Fix Trigonometric period
-         if rem >=  PIDIV2 { inv = !inv; rem = rem - PI }
-    else if rem <  -PIDIV2 { inv = !inv; rem = rem + PI };
+         if rem >=  PIDIV2 { inv = !inv; rem -= PI }
+    else if rem <  -PIDIV2 { inv = !inv; rem += PI };
     // Redirect value into right series
-         if rem >=  PIDIV4 { inv =  inv; rem = rem - PIDIV2; sel = false; }
-    else if rem <  -PIDIV4 { inv = !inv; rem = rem + PIDIV2; sel = false; };
+         if rem >=  PIDIV4 {             rem -= PIDIV2; sel = false; }
+    else if rem <  -PIDIV4 { inv = !inv; rem += PIDIV2; sel = false; };
     // Return result
     (sel, inv, rem)
 }
@@ -165,58 +180,274 @@ fn sin_prepare(
 //##########################################################################################################################
 
 #[inline]
-pub fn d_cos(
+fn d_cos_helper(
     value: Decimal,
-    terms: usize
-) -> Result<Decimal, Error> {
+    terms: Terms
+) -> Result<(Decimal, usize), Error> {
     let rem: Decimal = trig_prepare(value);
     Ok(
-             if rem ==  PI     { -D1 }
-        else if rem ==  PIDIV2 {  D0 }
-        else if rem ==  D0     {  D1 }
-        else if rem == -PIDIV2 {  D0 }
-        else if rem == -PI     { -D1 }
+             if rem ==  PI     { (-D1, 0) }
+        else if rem ==  PIDIV2 { ( D0, 0) }
+        else if rem ==  D0     { ( D1, 0) }
+        else if rem == -PIDIV2 { ( D0, 0) }
+        else if rem == -PI     { (-D1, 0) }
         else {
             let (sel, inv, rem) = cos_prepare(rem);
-            let res =
+            let (res, used) =
                 if sel { sin_series(rem, terms)? }
                 else   { cos_series(rem, terms)? };
-            res * if inv {-D1} else {D1}
+            (res * if inv {-D1} else {D1}, used)
         }
     )
 }
 
-//##########################################################################################################################
-
 #[inline]
-pub fn d_sin(
+pub fn d_cos(
     value: Decimal,
     terms: usize
 ) -> Result<Decimal, Error> {
+    d_cos_helper(value, Terms::Fixed(terms))
+        .map(|(res, _)| res)
+        .map_err(|e| e.context("d_cos", value))
+}
+
+/// cos(x) to `digits` decimal places, with the number of series terms used.
+#[inline]
+pub fn d_cos_dp(
+    value: Decimal,
+    digits: u32
+) -> Result<(Decimal, usize), Error> {
+    if digits > 28 { Err(Error::from(ErrorKind::InputOutOfRange).context("d_cos_dp", digits))? };
+    d_cos_helper(value, Terms::Digits(digits))
+        .map_err(|e| e.context("d_cos_dp", value))
+}
+
+//##########################################################################################################################
+
+#[inline]
+fn d_sin_helper(
+    value: Decimal,
+    terms: Terms
+) -> Result<(Decimal, usize), Error> {
     let rem: Decimal = trig_prepare(value);
     Ok(
-             if rem ==  PI     {  D0 }
-        else if rem ==  PIDIV2 {  D1 }
-        else if rem ==  D0     {  D0 }
-        else if rem == -PIDIV2 { -D1 }
-        else if rem == -PI     {  D0 }
+             if rem ==  PI     { ( D0, 0) }
+        else if rem ==  PIDIV2 { ( D1, 0) }
+        else if rem ==  D0     { ( D0, 0) }
+        else if rem == -PIDIV2 { (-D1, 0) }
+        else if rem == -PI     { ( D0, 0) }
         else {
             let (sel, inv, rem) = sin_prepare(rem);
-            let res =
+            let (res, used) =
                 if sel { sin_series(rem, terms)? }
                 else   { cos_series(rem, terms)? };
-            res * if inv {-D1} else {D1}
+            (res * if inv {-D1} else {D1}, used)
+        }
+    )
+}
+
+#[inline]
+pub fn d_sin(
+    value: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    d_sin_helper(value, Terms::Fixed(terms))
+        .map(|(res, _)| res)
+        .map_err(|e| e.context("d_sin", value))
+}
+
+/// sin(x) to `digits` decimal places, with the number of series terms used.
+#[inline]
+pub fn d_sin_dp(
+    value: Decimal,
+    digits: u32
+) -> Result<(Decimal, usize), Error> {
+    if digits > 28 { Err(Error::from(ErrorKind::InputOutOfRange).context("d_sin_dp", digits))? };
+    d_sin_helper(value, Terms::Digits(digits))
+        .map_err(|e| e.context("d_sin_dp", value))
+}
+
+//##########################################################################################################################
+
+/// (cos(x), sin(x)) sharing a single evaluation of each series
+#[inline]
+fn sin_cos(
+    value: Decimal,
+    terms: Terms
+) -> Result<(Pair, usize), Error> {
+    let rem: Decimal = trig_prepare(value);
+    Ok(
+             if rem ==  PI     { ((-D1,  D0), 0) }
+        else if rem ==  PIDIV2 { (( D0,  D1), 0) }
+        else if rem ==  D0     { (( D1,  D0), 0) }
+        else if rem == -PIDIV2 { (( D0, -D1), 0) }
+        else if rem == -PI     { ((-D1,  D0), 0) }
+        else {
+            let (sel_cos, inv_cos, red) = cos_prepare(rem);
+            let (sel_sin, inv_sin, _) = sin_prepare(rem);
+            let (res_sin, used_sin) = sin_series(red, terms)?;
+            let (res_cos, used_cos) = cos_series(red, terms)?;
+            let _cos = if sel_cos {res_sin} else {res_cos};
+            let _sin = if sel_sin {res_sin} else {res_cos};
+            (
+                (
+                    _cos * if inv_cos {-D1} else {D1},
+                    _sin * if inv_sin {-D1} else {D1}
+                ),
+                used_sin + used_cos
+            )
         }
     )
 }
 
 //##########################################################################################################################
 
+/// tan(x) = sin(x) / cos(x)
+#[inline]
+fn d_tan_helper(
+    value: Decimal,
+    terms: Terms
+) -> Result<(Decimal, usize), Error> {
+    let ((_cos, _sin), used) = sin_cos(value, terms)?;
+    if (_cos == D0) || trig_pole(value, PIDIV2) { Err(ErrorKind::Pole)? };
+    Ok((_sin.checked_div(_cos).ok_or(ErrorKind::DivisionByZero)?, used))
+}
+
+#[inline]
+pub fn d_tan(
+    value: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    d_tan_helper(value, Terms::Fixed(terms))
+        .map(|(res, _)| res)
+        .map_err(|e| e.context("d_tan", value))
+}
+
+/// tan(x) to `digits` decimal places, with the number of series terms used.
+#[inline]
+pub fn d_tan_dp(
+    value: Decimal,
+    digits: u32
+) -> Result<(Decimal, usize), Error> {
+    if digits > 28 { Err(Error::from(ErrorKind::InputOutOfRange).context("d_tan_dp", digits))? };
+    d_tan_helper(value, Terms::Digits(digits))
+        .map_err(|e| e.context("d_tan_dp", value))
+}
+
+//##########################################################################################################################
+
+/// cot(x) = cos(x) / sin(x)
+#[inline]
+fn d_cot_helper(
+    value: Decimal,
+    terms: Terms
+) -> Result<(Decimal, usize), Error> {
+    let ((_cos, _sin), used) = sin_cos(value, terms)?;
+    if (_sin == D0) || trig_pole(value, D0) { Err(ErrorKind::Pole)? };
+    Ok((_cos.checked_div(_sin).ok_or(ErrorKind::DivisionByZero)?, used))
+}
+
+#[inline]
+pub fn d_cot(
+    value: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    d_cot_helper(value, Terms::Fixed(terms))
+        .map(|(res, _)| res)
+        .map_err(|e| e.context("d_cot", value))
+}
+
+/// cot(x) to `digits` decimal places, with the number of series terms used.
+#[inline]
+pub fn d_cot_dp(
+    value: Decimal,
+    digits: u32
+) -> Result<(Decimal, usize), Error> {
+    if digits > 28 { Err(Error::from(ErrorKind::InputOutOfRange).context("d_cot_dp", digits))? };
+    d_cot_helper(value, Terms::Digits(digits))
+        .map_err(|e| e.context("d_cot_dp", value))
+}
+
+//##########################################################################################################################
+
+/// sec(x) = 1 / cos(x)
+#[inline]
+fn d_sec_helper(
+    value: Decimal,
+    terms: Terms
+) -> Result<(Decimal, usize), Error> {
+    let ((_cos, _), used) = sin_cos(value, terms)?;
+    if (_cos == D0) || trig_pole(value, PIDIV2) { Err(ErrorKind::Pole)? };
+    Ok((D1.checked_div(_cos).ok_or(ErrorKind::DivisionByZero)?, used))
+}
+
+#[inline]
+pub fn d_sec(
+    value: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    d_sec_helper(value, Terms::Fixed(terms))
+        .map(|(res, _)| res)
+        .map_err(|e| e.context("d_sec", value))
+}
+
+/// sec(x) to `digits` decimal places, with the number of series terms used.
 #[inline]
-fn is_valid_pair(_cos: Decimal, _sin: Decimal, terms: usize) -> bool {
-    let digits = if terms > 32 {16} else {terms / 2} as u32;
-    let module = (_cos * _cos) + (_sin * _sin);
-    D1 == module.round_dp(digits)
+pub fn d_sec_dp(
+    value: Decimal,
+    digits: u32
+) -> Result<(Decimal, usize), Error> {
+    if digits > 28 { Err(Error::from(ErrorKind::InputOutOfRange).context("d_sec_dp", digits))? };
+    d_sec_helper(value, Terms::Digits(digits))
+        .map_err(|e| e.context("d_sec_dp", value))
+}
+
+//##########################################################################################################################
+
+/// csc(x) = 1 / sin(x)
+#[inline]
+fn d_csc_helper(
+    value: Decimal,
+    terms: Terms
+) -> Result<(Decimal, usize), Error> {
+    let ((_, _sin), used) = sin_cos(value, terms)?;
+    if (_sin == D0) || trig_pole(value, D0) { Err(ErrorKind::Pole)? };
+    Ok((D1.checked_div(_sin).ok_or(ErrorKind::DivisionByZero)?, used))
+}
+
+#[inline]
+pub fn d_csc(
+    value: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    d_csc_helper(value, Terms::Fixed(terms))
+        .map(|(res, _)| res)
+        .map_err(|e| e.context("d_csc", value))
+}
+
+/// csc(x) to `digits` decimal places, with the number of series terms used.
+#[inline]
+pub fn d_csc_dp(
+    value: Decimal,
+    digits: u32
+) -> Result<(Decimal, usize), Error> {
+    if digits > 28 { Err(Error::from(ErrorKind::InputOutOfRange).context("d_csc_dp", digits))? };
+    d_csc_helper(value, Terms::Digits(digits))
+        .map_err(|e| e.context("d_csc_dp", value))
+}
+
+//##########################################################################################################################
+
+#[inline]
+fn is_valid_pair(_cos: Decimal, _sin: Decimal, terms: Terms) -> bool {
+    let digits = match terms {
+        Terms::Fixed(n)  => if n > 32 {16} else {(n / 2) as u32},
+        Terms::Digits(d) => if d > 16 {16} else {d},
+    };
+    let module = _cos.checked_mul(_cos)
+        .zip(_sin.checked_mul(_sin))
+        .and_then(|(c, s)| c.checked_add(s));
+    module.map(|m| m.round_dp(digits)) == Some(D1)
 }
 
 //##########################################################################################################################
@@ -225,16 +456,16 @@ fn is_valid_pair(_cos: Decimal, _sin: Decimal, terms: usize) -> bool {
 fn tan_lower(
     value: Decimal,
     offset: Decimal
-) -> (Decimal, Decimal) {
+) -> Result<(Decimal, Decimal), Error> {
     let mut rem: Decimal = value;
     let mut base: Decimal = offset;
     loop {
-             if rem < D1DIV5 { break;                                                  }
-        else if rem > D1     { base = base + PIDIV6;  rem = tan_sub(rem, TAN_PIDIV6);  }
-        else if rem > D2DIV5 { base = base + PIDIV18; rem = tan_sub(rem, TAN_PIDIV18); }
-        else                 { base = base + PIDIV36; rem = tan_sub(rem, TAN_PIDIV36); };
+             if rem < D1DIV5 { break;                                                   }
+        else if rem > D1     { base += PIDIV6;  rem = tan_sub(rem, TAN_PIDIV6)?;  }
+        else if rem > D2DIV5 { base += PIDIV18; rem = tan_sub(rem, TAN_PIDIV18)?; }
+        else                 { base += PIDIV36; rem = tan_sub(rem, TAN_PIDIV36)?; };
     };
-    (rem, base)
+    Ok((rem, base))
 }
 
 //##########################################################################################################################
@@ -242,12 +473,12 @@ fn tan_lower(
 #[inline]
 fn tan_prepare(
     value: Decimal
-) -> (Decimal, Decimal) {
+) -> Result<(Decimal, Decimal), Error> {
     let mut rem: Decimal = value;
     let mut base: Decimal = D0;
          if rem >=  D0 {                                                        }
-    else if rem >  -D1 { base = -PIDIV4; rem = tan_sub(rem, -D1);               }
-    else if rem <= -D1 { base = -PIDIV2; rem = tan_sub(tan_sub(rem, -D1), -D1); };
+    else if rem >  -D1 { base = -PIDIV4; rem = tan_sub(rem, -D1)?;                }
+    else if rem <= -D1 { base = -PIDIV2; rem = tan_sub(tan_sub(rem, -D1)?, -D1)?; };
     tan_lower(rem, base)
 }
 
@@ -257,12 +488,12 @@ fn tan_prepare(
 fn tan2_prepare(
     _cos: Decimal,
     _sin: Decimal
-) -> (Decimal, Decimal) {
-    let mut rem: Decimal = _sin / _cos;
-    let mut base: Decimal = D0;
-         if (_sin >  D0) && (_cos <= D0) { base = PIDIV2;  rem = tan_sub2((_cos, _sin), PIDIV2_PAIR);  }
-    else if (_sin <= D0) && (_cos <  D0) { base = PI;      rem = tan_sub2((_cos, _sin), PI_PAIR);      }
-    else if (_sin <  D0) && (_cos >= D0) { base = PI3DIV2; rem = tan_sub2((_cos, _sin), PI3DIV2_PAIR); };
+) -> Result<(Decimal, Decimal), Error> {
+    let (rem, base) =
+             if (_sin >  D0) && (_cos <= D0) { (tan_sub2((_cos, _sin), PIDIV2_PAIR)?,  PIDIV2)  }
+        else if (_sin <= D0) && (_cos <  D0) { (tan_sub2((_cos, _sin), PI_PAIR)?,      PI)      }
+        else if (_sin <  D0) && (_cos >= D0) { (tan_sub2((_cos, _sin), PI3DIV2_PAIR)?, PI3DIV2) }
+        else { (_sin.checked_div(_cos).ok_or(ErrorKind::DivisionByZero)?, D0) };
     tan_lower(rem, base)
 }
 
@@ -272,61 +503,216 @@ fn tan2_prepare(
 #[inline]
 fn atan_series(
     value: Decimal,
-    terms: usize
-) -> Result<Decimal, Error> {
+    terms: Terms
+) -> Result<(Decimal, usize), Error> {
     let mut acc1: (Decimal, usize) = (D1, 0);
     let mut acc2: (Multiplex, usize) = (Multiplex::new(), 0);
     // Iterate over Series
-    (0..terms).into_iter()
-        .map(|n| Ok(
-            da_pow(-D1, n, &mut acc1)? * (
-                ma_pow(value, (2 * n) + 1, &mut acc2)? / ((D2 * dec(n)) + D1)
-            ).squash()?
-        ))
-        .reduce(|u, d| Ok(
-            u?.checked_add(d?).ok_or(Error::AddOverflow)?
-        ))
-        .unwrap_or(Ok(D0))
+    d_series(0, terms, |n| Ok(
+        da_pow(-D1, n, &mut acc1)? * (
+            ma_pow(value, (2 * n) + 1, &mut acc2)? / ((D2 * dec(n)) + D1)
+        ).squash()?
+    ))
 }
 
 //##########################################################################################################################
 
+#[inline]
+fn d_atan_helper(
+    value: Decimal,
+    terms: Terms
+) -> Result<(Decimal, usize), Error> {
+    Ok(
+             if value ==  D0 { ( D0,     0) }
+        else if value ==  D1 { ( PIDIV4, 0) }
+        else if value == -D1 { (-PIDIV4, 0) }
+        else {
+            let (rem, base) = tan_prepare(value)?;
+            let (res, used) = atan_series(rem, terms)?;
+            (base + res, used)
+        }
+    )
+}
+
 #[inline]
 pub fn d_atan(
     value: Decimal,
     terms: usize
 ) -> Result<Decimal, Error> {
+    d_atan_helper(value, Terms::Fixed(terms))
+        .map(|(res, _)| res)
+        .map_err(|e| e.context("d_atan", value))
+}
+
+/// atan(x) to `digits` decimal places, with the number of series terms used.
+#[inline]
+pub fn d_atan_dp(
+    value: Decimal,
+    digits: u32
+) -> Result<(Decimal, usize), Error> {
+    if digits > 28 { Err(Error::from(ErrorKind::InputOutOfRange).context("d_atan_dp", digits))? };
+    d_atan_helper(value, Terms::Digits(digits))
+        .map_err(|e| e.context("d_atan_dp", value))
+}
+
+//##########################################################################################################################
+
+#[inline]
+fn d_atan2_helper(
+    _cos: Decimal,
+    _sin: Decimal,
+    terms: Terms
+) -> Result<(Decimal, usize), Error> {
+    if !is_valid_pair(_cos, _sin, terms) { Err(ErrorKind::InputOutOfRange)? };
     Ok(
-             if value ==  D0 {  D0     }
-        else if value ==  D1 {  PIDIV4 }
-        else if value == -D1 { -PIDIV4 }
+             if (_cos >  D0) && (_sin == D0) { ( D0,     0) }
+        else if (_cos == D0) && (_sin >  D0) { ( PIDIV2, 0) }
+        else if (_cos <  D0) && (_sin == D0) { ( PI,     0) }
+        else if (_cos == D0) && (_sin <  D0) { (-PIDIV2, 0) }
         else {
-            let (rem, base) = tan_prepare(value);
-            base + atan_series(rem, terms)?
+            let (rem, base) = tan2_prepare(_cos, _sin)?;
+            let (res, used) = atan_series(rem, terms)?;
+            let arg = base + res;
+            (if arg <= PI {arg} else {arg - PI2}, used)
         }
     )
 }
 
-//##########################################################################################################################
-
 #[inline]
 pub fn d_atan2(
     _cos: Decimal,
     _sin: Decimal,
     terms: usize
 ) -> Result<Decimal, Error> {
-    if !is_valid_pair(_cos, _sin, terms) { Err(Error::InputOutOfRange)? };
+    d_atan2_helper(_cos, _sin, Terms::Fixed(terms))
+        .map(|(res, _)| res)
+        .map_err(|e| e.context("d_atan2", format!("{}, {}", _cos, _sin)))
+}
+
+/// atan2(cos, sin) to `digits` decimal places, with the number of series terms used.
+#[inline]
+pub fn d_atan2_dp(
+    _cos: Decimal,
+    _sin: Decimal,
+    digits: u32
+) -> Result<(Decimal, usize), Error> {
+    if digits > 28 { Err(Error::from(ErrorKind::InputOutOfRange).context("d_atan2_dp", digits))? };
+    d_atan2_helper(_cos, _sin, Terms::Digits(digits))
+        .map_err(|e| e.context("d_atan2_dp", format!("{}, {}", _cos, _sin)))
+}
+
+//##########################################################################################################################
+
+/// asin(x) = atan(x / sqrt(1 - x^2))
+#[inline]
+fn asin_lower(
+    value: Decimal,
+    terms: Terms
+) -> Result<(Decimal, usize), Error> {
+    let (root, used1) = d_sqrt_helper((D1 - value) * (D1 + value), terms)?;
+    let (res, used2) = d_atan_helper(value / root, terms)?;
+    Ok((res, used1 + used2))
+}
+
+/// acos(x) = 2 * asin(sqrt((1 - x) / 2))
+#[inline]
+fn acos_upper(
+    value: Decimal,
+    terms: Terms
+) -> Result<(Decimal, usize), Error> {
+    let (root, used1) = d_sqrt_helper((D1 - value) / D2, terms)?;
+    let (res, used2) = asin_lower(root, terms)?;
+    Ok((D2 * res, used1 + used2))
+}
+
+//##########################################################################################################################
+
+#[inline]
+fn d_asin_helper(
+    value: Decimal,
+    terms: Terms
+) -> Result<(Decimal, usize), Error> {
+    if (value < -D1) || (D1 < value) { Err(ErrorKind::InputOutOfRange)? };
     Ok(
-             if (_cos >  D0) && (_sin == D0) {  D0     }
-        else if (_cos == D0) && (_sin >  D0) {  PIDIV2 }
-        else if (_cos <  D0) && (_sin == D0) {  PI     }
-        else if (_cos == D0) && (_sin <  D0) { -PIDIV2 }
+             if value ==  D1     { ( PIDIV2, 0) }
+        else if value ==  D1DIV2 { ( PIDIV6, 0) }
+        else if value ==  D0     { ( D0,     0) }
+        else if value == -D1DIV2 { (-PIDIV6, 0) }
+        else if value == -D1     { (-PIDIV2, 0) }
+        else if value.abs() < D1DIV2 { asin_lower(value, terms)? }
         else {
-            let (rem, base) = tan2_prepare(_cos, _sin);
-            let arg = base + atan_series(rem, terms)?;
-            if arg <= PI {arg} else {arg - PI2}
+            let (res, used) = acos_upper(value.abs(), terms)?;
+            let res = PIDIV2 - res;
+            (if value.is_sign_negative() {-res} else {res}, used)
         }
     )
 }
 
+#[inline]
+pub fn d_asin(
+    value: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    d_asin_helper(value, Terms::Fixed(terms))
+        .map(|(res, _)| res)
+        .map_err(|e| e.context("d_asin", value))
+}
+
+/// asin(x) to `digits` decimal places, with the number of series terms used.
+#[inline]
+pub fn d_asin_dp(
+    value: Decimal,
+    digits: u32
+) -> Result<(Decimal, usize), Error> {
+    if digits > 28 { Err(Error::from(ErrorKind::InputOutOfRange).context("d_asin_dp", digits))? };
+    d_asin_helper(value, Terms::Digits(digits))
+        .map_err(|e| e.context("d_asin_dp", value))
+}
+
+//##########################################################################################################################
+
+#[inline]
+fn d_acos_helper(
+    value: Decimal,
+    terms: Terms
+) -> Result<(Decimal, usize), Error> {
+    if (value < -D1) || (D1 < value) { Err(ErrorKind::InputOutOfRange)? };
+    Ok(
+             if value ==  D1     { (D0,      0) }
+        else if value ==  D1DIV2 { (PIDIV3,  0) }
+        else if value ==  D0     { (PIDIV2,  0) }
+        else if value == -D1DIV2 { (PI2DIV3, 0) }
+        else if value == -D1     { (PI,      0) }
+        else if value.abs() < D1DIV2 {
+            let (res, used) = asin_lower(value, terms)?;
+            (PIDIV2 - res, used)
+        }
+        else {
+            let (res, used) = acos_upper(value.abs(), terms)?;
+            (if value.is_sign_negative() {PI - res} else {res}, used)
+        }
+    )
+}
+
+#[inline]
+pub fn d_acos(
+    value: Decimal,
+    terms: usize
+) -> Result<Decimal, Error> {
+    d_acos_helper(value, Terms::Fixed(terms))
+        .map(|(res, _)| res)
+        .map_err(|e| e.context("d_acos", value))
+}
+
+/// acos(x) to `digits` decimal places, with the number of series terms used.
+#[inline]
+pub fn d_acos_dp(
+    value: Decimal,
+    digits: u32
+) -> Result<(Decimal, usize), Error> {
+    if digits > 28 { Err(Error::from(ErrorKind::InputOutOfRange).context("d_acos_dp", digits))? };
+    d_acos_helper(value, Terms::Digits(digits))
+        .map_err(|e| e.context("d_acos_dp", value))
+}
+
 //##########################################################################################################################